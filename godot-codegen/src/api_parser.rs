@@ -42,6 +42,7 @@ pub struct BuiltinClass {
     pub constructors: Vec<Constructor>,
     pub has_destructor: bool,
     pub operators: Vec<Operator>,
+    pub methods: Option<Vec<BuiltinClassMethod>>,
 }
 
 #[derive(DeJson)]
@@ -115,6 +116,15 @@ pub struct Constructor {
     pub arguments: Option<Vec<MethodArg>>,
 }
 
+#[derive(DeJson)]
+pub struct BuiltinClassMethod {
+    pub name: String,
+    pub return_type: Option<String>,
+    pub is_vararg: bool,
+    pub hash: i64,
+    pub arguments: Option<Vec<MethodArg>>,
+}
+
 #[derive(DeJson)]
 pub struct UtilityFunction {
     pub name: String,
@@ -49,6 +49,7 @@ struct BuiltinTypeInfo<'a> {
     has_destructor: bool,
     constructors: Option<&'a Vec<Constructor>>,
     operators: Option<&'a Vec<Operator>>,
+    methods: Option<&'a Vec<BuiltinClassMethod>>,
 }
 
 pub(crate) fn generate_central_files(
@@ -186,15 +187,25 @@ fn make_core_code(central_items: &CentralItems) -> String {
         ..
     } = central_items;
 
-    // TODO impl Clone, Debug, PartialEq, PartialOrd, Hash for VariantDispatch
-    // TODO could use try_to().unwrap_unchecked(), since type is already verified. Also directly overload from_variant().
-    // But this requires that all the variant types support this
+    // Objects need share() instead of clone(), and are compared by identity
+    let value_pascal: Vec<_> = variant_ty_enumerators_pascal
+        .iter()
+        .filter(|pascal| *pascal != "Object")
+        .collect();
+
+    // TODO impl PartialOrd, Hash for VariantDispatch
+    // TODO could use try_to().unwrap_unchecked() for non-object types, since type is already verified
     let core_tokens = quote! {
         use crate::builtin::*;
         use crate::engine::Object;
-        use crate::obj::Gd;
-
-        #[allow(dead_code)]
+        use crate::obj::{Gd, Share};
+        use crate::sys;
+
+        /// The value of a [`Variant`], converted to the Rust type corresponding to its [`VariantType`].
+        ///
+        /// Obtained through [`Variant::dispatch()`], this allows exhaustive `match`es on a variant's contents.
+        /// Null and freed objects are dispatched as `Nil`.
+        #[derive(Debug)]
         pub enum VariantDispatch {
             Nil,
             #(
@@ -202,14 +213,15 @@ fn make_core_code(central_items: &CentralItems) -> String {
             )*
         }
 
-        #[cfg(FALSE)]
         impl FromVariant for VariantDispatch {
             fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+                // The type is already verified, so conversions only fail for null and freed objects
                 let dispatch = match variant.get_type() {
                     VariantType::Nil => Self::Nil,
                     #(
-                        VariantType::#variant_ty_enumerators_pascal
-                            => Self::#variant_ty_enumerators_pascal(variant.to::<#variant_ty_enumerators_rust>()),
+                        VariantType::#variant_ty_enumerators_pascal => variant
+                            .try_to::<#variant_ty_enumerators_rust>()
+                            .map_or(Self::Nil, Self::#variant_ty_enumerators_pascal),
                     )*
                 };
 
@@ -217,6 +229,56 @@ fn make_core_code(central_items: &CentralItems) -> String {
             }
         }
 
+        impl ToVariant for VariantDispatch {
+            fn to_variant(&self) -> Variant {
+                match self {
+                    Self::Nil => Variant::nil(),
+                    #(
+                        Self::#variant_ty_enumerators_pascal(value) => value.to_variant(),
+                    )*
+                }
+            }
+        }
+
+        impl From<VariantDispatch> for Variant {
+            fn from(dispatch: VariantDispatch) -> Self {
+                dispatch.to_variant()
+            }
+        }
+
+        impl Clone for VariantDispatch {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Nil => Self::Nil,
+                    Self::Object(object) => Self::Object(object.share()),
+                    #(
+                        Self::#value_pascal(value) => Self::#value_pascal(value.clone()),
+                    )*
+                }
+            }
+        }
+
+        impl PartialEq for VariantDispatch {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Nil, Self::Nil) => true,
+                    (Self::Object(lhs), Self::Object(rhs)) => {
+                        lhs.instance_id_or_none() == rhs.instance_id_or_none()
+                    }
+                    #(
+                        (Self::#value_pascal(lhs), Self::#value_pascal(rhs)) => lhs == rhs,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl VariantMetadata for VariantDispatch {
+            fn variant_type() -> sys::GDNativeVariantType {
+                sys::GDNATIVE_VARIANT_TYPE_NIL
+            }
+        }
+
         pub mod global {
             use crate::sys;
             #( #global_enum_defs )*
@@ -269,6 +331,7 @@ fn make_central_items(api: &ExtensionApi, build_config: &str, ctx: &mut Context)
             ty.has_destructor,
             ty.constructors,
             ty.operators,
+            ty.methods,
             &builtin_types_map,
         );
 
@@ -354,17 +417,20 @@ fn collect_builtin_types<'a>(
         let has_destructor: bool;
         let constructors: Option<&Vec<Constructor>>;
         let operators: Option<&Vec<Operator>>;
+        let methods: Option<&Vec<BuiltinClassMethod>>;
         if let Some(class) = class_map.get(&normalized) {
             pascal_case = class.name.clone();
             has_destructor = class.has_destructor;
             constructors = Some(&class.constructors);
             operators = Some(&class.operators);
+            methods = class.methods.as_ref();
         } else {
             assert_eq!(normalized, "object");
             pascal_case = "Object".to_string();
             has_destructor = false;
             constructors = None;
             operators = None;
+            methods = None;
         }
 
         let type_names = TypeNames {
@@ -384,6 +450,7 @@ fn collect_builtin_types<'a>(
                 has_destructor,
                 constructors,
                 operators,
+                methods,
             },
         );
     }
@@ -431,6 +498,7 @@ fn make_variant_fns(
     has_destructor: bool,
     constructors: Option<&Vec<Constructor>>,
     operators: Option<&Vec<Operator>>,
+    methods: Option<&Vec<BuiltinClassMethod>>,
     builtin_types: &HashMap<String, BuiltinTypeInfo>,
 ) -> (TokenStream, TokenStream) {
     let (construct_decls, construct_inits) =
@@ -438,6 +506,7 @@ fn make_variant_fns(
     let (destroy_decls, destroy_inits) = make_destroy_fns(type_names, has_destructor);
     let (op_eq_decls, op_eq_inits) = make_operator_fns(type_names, operators, "==", "EQUAL");
     let (op_lt_decls, op_lt_inits) = make_operator_fns(type_names, operators, "<", "LESS");
+    let (method_decls, method_inits) = make_builtin_method_fns(type_names, methods);
 
     let to_variant = format_ident!("{}_to_variant", type_names.snake_case);
     let from_variant = format_ident!("{}_from_variant", type_names.snake_case);
@@ -456,6 +525,7 @@ fn make_variant_fns(
         #op_lt_decls
         #construct_decls
         #destroy_decls
+        #method_decls
     };
 
     // Field initialization in new()
@@ -472,6 +542,7 @@ fn make_variant_fns(
         #op_lt_inits
         #construct_inits
         #destroy_inits
+        #method_inits
     };
 
     (decl, init)
@@ -659,6 +730,46 @@ fn make_operator_fns(
     (decl, init)
 }
 
+/// Loads the ptrcall functions of a builtin type's methods, e.g. `Dictionary.keys()` => `dictionary_keys`
+fn make_builtin_method_fns(
+    type_names: &TypeNames,
+    methods: Option<&Vec<BuiltinClassMethod>>,
+) -> (TokenStream, TokenStream) {
+    let methods = match methods {
+        Some(m) if has_builtin_methods(type_names) => m,
+        _ => return (TokenStream::new(), TokenStream::new()),
+    };
+
+    let variant_type = &type_names.sys_variant_type;
+    let mut decls = Vec::with_capacity(methods.len());
+    let mut inits = Vec::with_capacity(methods.len());
+
+    for method in methods {
+        // TODO support vararg methods (need varcall instead of ptrcall)
+        if method.is_vararg {
+            continue;
+        }
+
+        let ident = format_ident!("{}_{}", type_names.snake_case, method.name);
+        let c_method_name = util::c_str(&method.name);
+        let hash = method.hash;
+        let error = format_load_error(&ident);
+
+        // Generic signature:  fn(base: GDNativeTypePtr, args: *const GDNativeTypePtr, ret: GDNativeTypePtr, arg_count: i32)
+        decls.push(quote! {
+            pub #ident: unsafe extern "C" fn(GDNativeTypePtr, *const GDNativeTypePtr, GDNativeTypePtr, i32),
+        });
+        inits.push(quote! {
+            #ident: {
+                let method_fn = interface.variant_get_ptr_builtin_method.unwrap();
+                method_fn(crate:: #variant_type, #c_method_name, #hash).expect(#error)
+            },
+        });
+    }
+
+    (quote! { #(#decls)* }, quote! { #(#inits)* })
+}
+
 fn format_load_error(ident: &impl std::fmt::Display) -> String {
     format!(
         "failed to load GDExtension function `{}`",
@@ -674,6 +785,31 @@ fn is_trivial(type_names: &TypeNames) -> bool {
     list.contains(&type_names.pascal_case.as_str())
 }
 
+/// Returns true if the type's builtin methods are loaded into the method table, to be called from Rust.
+///
+/// Only types whose Rust API delegates to Godot are listed, to keep the number of function lookups at startup low.
+fn has_builtin_methods(type_names: &TypeNames) -> bool {
+    let list = [
+        "Array",
+        "Callable",
+        "Dictionary",
+        "NodePath",
+        "PackedByteArray",
+        "PackedColorArray",
+        "PackedFloat32Array",
+        "PackedFloat64Array",
+        "PackedInt32Array",
+        "PackedInt64Array",
+        "PackedStringArray",
+        "PackedVector2Array",
+        "PackedVector3Array",
+        "Signal",
+        "String",
+    ];
+
+    list.contains(&type_names.pascal_case.as_str())
+}
+
 fn shout_to_pascal(shout_case: &str) -> String {
     let mut result = String::with_capacity(shout_case.len());
     let mut next_upper = true;
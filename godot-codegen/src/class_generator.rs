@@ -522,6 +522,10 @@ fn make_method_return(
             // If the return type is not Variant, then convert to concrete target type
             let return_expr = match return_ty {
                 RustTy::BuiltinIdent(ident) if ident == "Variant" => quote! { variant },
+                RustTy::BuiltinIdent(ident) if ident == "Rid" => quote! {
+                    let rid: Rid = variant.to();
+                    rid.is_valid().then_some(rid)
+                },
                 _ => quote! { variant.to() },
             };
 
@@ -543,7 +547,7 @@ fn make_method_return(
                 assert_eq!(err.error, sys::GDNATIVE_CALL_OK);
             }
         }
-        (false, Some(RustTy::EngineClass(return_ty))) => {
+        (false, Some(return_ty)) if return_ty.is_nullable() => {
             quote! {
                 <#return_ty>::from_sys_init_opt(|return_ptr| {
                     call_fn(method_bind, self.object_ptr, args_ptr, return_ptr);
@@ -584,7 +588,7 @@ fn make_utility_return(
     }
 
     let call = match return_ty {
-        Some(RustTy::EngineClass(return_ty)) => {
+        Some(return_ty) if return_ty.is_nullable() => {
             quote! {
                 <#return_ty>::from_sys_init_opt(|return_ptr| {
                     call_fn(return_ptr, args_ptr, args.len() as i32);
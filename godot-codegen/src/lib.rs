@@ -132,9 +132,22 @@ enum RustTy {
 
 impl RustTy {
     pub fn return_decl(&self) -> TokenStream {
+        let ty = self;
+        if ty.is_nullable() {
+            quote! { -> Option<#ty> }
+        } else {
+            quote! { -> #ty }
+        }
+    }
+
+    /// Whether Godot may return a null value of this type, which is mapped to `None`.
+    ///
+    /// Such types provide a `from_sys_init_opt()` constructor.
+    pub fn is_nullable(&self) -> bool {
         match self {
-            Self::EngineClass(tokens) => quote! { -> Option<#tokens> },
-            other => quote! { -> #other },
+            Self::EngineClass(_) => true,
+            Self::BuiltinIdent(ident) => ident == "Rid",
+            _ => false,
         }
     }
 }
@@ -93,6 +93,26 @@ pub fn make_enum_definition(enum_: &dyn Enum) -> TokenStream {
         impl sys::GodotFfi for #enum_name {
             sys::ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
         }
+        // Not a blanket impl over EngineEnum, which would conflict with impls for fundamental types like Box<T>
+        impl crate::builtin::ToVariant for #enum_name {
+            fn to_variant(&self) -> crate::builtin::Variant {
+                crate::builtin::ToVariant::to_variant(&self.ord)
+            }
+        }
+        impl crate::builtin::FromVariant for #enum_name {
+            fn try_from_variant(
+                variant: &crate::builtin::Variant
+            ) -> Result<Self, crate::builtin::VariantConversionError> {
+                let ord = <i32 as crate::builtin::FromVariant>::try_from_variant(variant)?;
+                <Self as crate::obj::EngineEnum>::try_from_ord(ord).ok_or_else(|| {
+                    crate::builtin::VariantConversionError::new(
+                        crate::builtin::VariantType::Int,
+                        variant,
+                        crate::builtin::VariantConversionReason::OutOfRange,
+                    )
+                })
+            }
+        }
         #bitfield_ops
     }
 }
@@ -235,6 +255,7 @@ fn to_hardcoded_rust_type(ty: &str) -> Option<&str> {
         "int" => "i64",
         "float" => "f64",
         "String" => "GodotString",
+        "RID" => "Rid",
         //"enum::Error" => "GodotError",
         "enum::Variant.Type" => "VariantType",
         "enum::Variant.Operator" => "VariantOperator", // currently not used, but future-proof
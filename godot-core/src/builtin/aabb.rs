@@ -0,0 +1,255 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::Vector3;
+
+/// Axis-aligned bounding box in 3D space.
+///
+/// Most operations assume a non-negative `size`; use [`AABB::abs()`] to normalize a box first.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct AABB {
+    pub position: Vector3,
+    pub size: Vector3,
+}
+
+impl AABB {
+    pub const fn new(position: Vector3, size: Vector3) -> Self {
+        Self { position, size }
+    }
+
+    /// Corner opposite to `position`, i.e. `position + size`.
+    pub fn end(self) -> Vector3 {
+        self.position + self.size
+    }
+
+    /// Moves the corner opposite to `position` to `end`, keeping `position`.
+    pub fn set_end(&mut self, end: Vector3) {
+        self.size = end - self.position;
+    }
+
+    pub fn center(self) -> Vector3 {
+        self.position + self.size * 0.5
+    }
+
+    pub fn volume(self) -> f32 {
+        self.size.x() * self.size.y() * self.size.z()
+    }
+
+    /// Returns `true` if all three extents are positive.
+    pub fn has_volume(self) -> bool {
+        self.size.x() > 0.0 && self.size.y() > 0.0 && self.size.z() > 0.0
+    }
+
+    /// Returns `true` if `point` lies inside the box or on its surface.
+    pub fn has_point(self, point: Vector3) -> bool {
+        let (begin, end) = (to_array(self.position), to_array(self.end()));
+        let point = to_array(point);
+
+        (0..3).all(|i| point[i] >= begin[i] && point[i] <= end[i])
+    }
+
+    /// Returns `true` if the two boxes overlap. Boxes which only touch at their faces do not.
+    pub fn intersects(self, other: Self) -> bool {
+        let (begin, end) = (to_array(self.position), to_array(self.end()));
+        let (other_begin, other_end) = (to_array(other.position), to_array(other.end()));
+
+        (0..3).all(|i| begin[i] < other_end[i] && end[i] > other_begin[i])
+    }
+
+    /// Returns the overlapping volume of both boxes, or `None` if they do not intersect.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let position = self.position.max(other.position);
+        let end = self.end().min(other.end());
+        Some(Self::new(position, end - position))
+    }
+
+    /// Returns the smallest box enclosing both `self` and `other`.
+    pub fn merge(self, other: Self) -> Self {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Self::new(position, end - position)
+    }
+
+    /// Returns `true` if `other` lies completely inside `self`, including its surface.
+    pub fn encloses(self, other: Self) -> bool {
+        let (begin, end) = (to_array(self.position), to_array(self.end()));
+        let (other_begin, other_end) = (to_array(other.position), to_array(other.end()));
+
+        (0..3).all(|i| begin[i] <= other_begin[i] && end[i] >= other_end[i])
+    }
+
+    /// Returns the box extended by `amount` on all six faces. Negative values shrink it.
+    pub fn grow(self, amount: f32) -> Self {
+        let amount = Vector3::ONE * amount;
+        Self::new(self.position - amount, self.size + amount * 2.0)
+    }
+
+    /// Returns the smallest box containing both `self` and `point`.
+    pub fn expand(self, point: Vector3) -> Self {
+        let position = self.position.min(point);
+        let end = self.end().max(point);
+        Self::new(position, end - position)
+    }
+
+    /// Returns an equivalent box with non-negative size, moving `position` to the minimum corner.
+    pub fn abs(self) -> Self {
+        Self::new(
+            self.position + self.size.min(Vector3::ZERO),
+            self.size.abs(),
+        )
+    }
+
+    /// Returns the corner which lies furthest in the given `direction`.
+    pub fn get_support(self, direction: Vector3) -> Vector3 {
+        let half_extents = self.size * 0.5;
+        let pick = |dir: f32, half: f32| if dir > 0.0 { half } else { -half };
+
+        self.center()
+            + Vector3::new(
+                pick(direction.x(), half_extents.x()),
+                pick(direction.y(), half_extents.y()),
+                pick(direction.z(), half_extents.z()),
+            )
+    }
+
+    /// Returns one of the 8 corners of the box.
+    ///
+    /// Bits 2, 1 and 0 of `index` select the far side along the X, Y and Z axis, respectively.
+    ///
+    /// # Panics
+    /// If `index` is not in `0..8`.
+    pub fn get_endpoint(self, index: usize) -> Vector3 {
+        assert!(index < 8, "AABB endpoint index {index} out of range 0..8");
+
+        let pick = |bit: usize, begin: f32, size: f32| {
+            if index & bit != 0 {
+                begin + size
+            } else {
+                begin
+            }
+        };
+
+        Vector3::new(
+            pick(4, self.position.x(), self.size.x()),
+            pick(2, self.position.y(), self.size.y()),
+            pick(1, self.position.z(), self.size.z()),
+        )
+    }
+
+    /// Returns the point where the ray starting at `from` in `direction` enters the box, or `None` if it misses.
+    ///
+    /// If `from` lies inside the box, `from` itself is returned.
+    pub fn intersects_ray(self, from: Vector3, direction: Vector3) -> Option<Vector3> {
+        let (begin, end) = (to_array(self.position), to_array(self.end()));
+        let (origin, dir) = (to_array(from), to_array(direction));
+
+        let mut near = f32::NEG_INFINITY;
+        let mut far = f32::INFINITY;
+
+        for i in 0..3 {
+            if dir[i] == 0.0 {
+                if origin[i] < begin[i] || origin[i] > end[i] {
+                    return None;
+                }
+            } else {
+                let t1 = (begin[i] - origin[i]) / dir[i];
+                let t2 = (end[i] - origin[i]) / dir[i];
+                near = near.max(t1.min(t2));
+                far = far.min(t1.max(t2));
+
+                if near > far || far < 0.0 {
+                    return None;
+                }
+            }
+        }
+
+        Some(from + direction * near.max(0.0))
+    }
+
+    /// Returns the first point where the segment from `from` to `to` touches the box, or `None` if it misses.
+    pub fn intersects_segment(self, from: Vector3, to: Vector3) -> Option<Vector3> {
+        let (begin, end) = (to_array(self.position), to_array(self.end()));
+        let (seg_from, seg_to) = (to_array(from), to_array(to));
+
+        let mut min = 0.0;
+        let mut max = 1.0;
+
+        for i in 0..3 {
+            let length = seg_to[i] - seg_from[i];
+
+            let (cmin, cmax) = if seg_from[i] < seg_to[i] {
+                if seg_from[i] > end[i] || seg_to[i] < begin[i] {
+                    return None;
+                }
+
+                let cmin = if seg_from[i] < begin[i] {
+                    (begin[i] - seg_from[i]) / length
+                } else {
+                    0.0
+                };
+                let cmax = if seg_to[i] > end[i] {
+                    (end[i] - seg_from[i]) / length
+                } else {
+                    1.0
+                };
+                (cmin, cmax)
+            } else {
+                if seg_to[i] > end[i] || seg_from[i] < begin[i] {
+                    return None;
+                }
+
+                let cmin = if seg_from[i] > end[i] {
+                    (end[i] - seg_from[i]) / length
+                } else {
+                    0.0
+                };
+                let cmax = if seg_to[i] < begin[i] {
+                    (begin[i] - seg_from[i]) / length
+                } else {
+                    1.0
+                };
+                (cmin, cmax)
+            };
+
+            if cmin > min {
+                min = cmin;
+            }
+            if cmax < max {
+                max = cmax;
+            }
+            if max < min {
+                return None;
+            }
+        }
+
+        Some(from + (to - from) * min)
+    }
+
+    /// Returns `true` if position and size are approximately equal, see [`Vector3::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.position.is_equal_approx(other.position) && self.size.is_equal_approx(other.size)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.position.is_finite() && self.size.is_finite()
+    }
+}
+
+impl GodotFfi for AABB {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+fn to_array(v: Vector3) -> [f32; 3] {
+    [v.x(), v.y(), v.z()]
+}
@@ -6,60 +6,348 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{FromVariant, Variant};
+use crate::builtin::{
+    try_from_ptr_sys, try_write_ptr_sys, FromVariant, GodotString, PtrcallMarshal, StringName,
+    ToVariant, Variant, VariantConversionError, VariantConversionReason, VariantMetadata,
+    VariantType,
+};
+use crate::engine::global::PropertyHint;
+use crate::obj::EngineEnum;
+use std::fmt;
 use std::marker::PhantomData;
 use sys::{ffi_methods, interface_fn, types::*, GodotFfi};
 
-impl_builtin_stub!(Array, OpaqueArray);
-impl_builtin_stub!(ByteArray, OpaquePackedByteArray);
-impl_builtin_stub!(ColorArray, OpaquePackedColorArray);
-impl_builtin_stub!(Float32Array, OpaquePackedFloat32Array);
-impl_builtin_stub!(Float64Array, OpaquePackedFloat64Array);
-impl_builtin_stub!(Int32Array, OpaquePackedInt32Array);
-impl_builtin_stub!(Int64Array, OpaquePackedInt64Array);
-impl_builtin_stub!(StringArray, OpaquePackedStringArray);
-impl_builtin_stub!(Vector2Array, OpaquePackedVector2Array);
-impl_builtin_stub!(Vector3Array, OpaquePackedVector3Array);
-
-impl_builtin_froms!(Array;
-    ByteArray => array_from_packed_byte_array,
-    ColorArray => array_from_packed_color_array,
-    Float32Array => array_from_packed_float32_array,
-    Float64Array => array_from_packed_float64_array,
-    Int32Array => array_from_packed_int32_array,
-    Int64Array => array_from_packed_int64_array,
-    StringArray => array_from_packed_string_array,
-    Vector2Array => array_from_packed_vector2_array,
-    Vector3Array => array_from_packed_vector3_array,
-);
-
-impl_builtin_froms!(ByteArray; Array => packed_byte_array_from_array);
-impl_builtin_froms!(ColorArray; Array => packed_color_array_from_array);
-impl_builtin_froms!(Float32Array; Array => packed_float32_array_from_array);
-impl_builtin_froms!(Float64Array; Array => packed_float64_array_from_array);
-impl_builtin_froms!(Int32Array; Array => packed_int32_array_from_array);
-impl_builtin_froms!(Int64Array; Array => packed_int64_array_from_array);
-impl_builtin_froms!(StringArray; Array => packed_string_array_from_array);
-impl_builtin_froms!(Vector2Array; Array => packed_vector2_array_from_array);
-impl_builtin_froms!(Vector3Array; Array => packed_vector3_array_from_array);
+/// Godot's `Array` type, an ordered list of [`Variant`]s.
+///
+/// Arrays are reference-counted: [`Clone`] creates a new reference to the _same_ array.
+/// Use [`Array::duplicate_shallow()`] or [`Array::duplicate_deep()`] for an independent copy.
+#[repr(C)]
+pub struct Array {
+    opaque: OpaqueArray,
+}
 
 impl Array {
-    pub fn get(&self, index: i64) -> Option<Variant> {
+    /// Creates an empty array.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        let len = unsafe { builtin_call!(array_size(self.sys()) -> i64) };
+        len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe { builtin_call!(array_is_empty(self.sys()) -> bool) }
+    }
+
+    /// Returns the element at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Variant> {
+        (index < self.len()).then(|| unsafe { (*self.ptr(index)).clone() })
+    }
+
+    /// Replaces the element at `index` with `value`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set<T: ToVariant>(&mut self, index: usize, value: T) {
+        self.check_bounds(index);
+
         unsafe {
-            let ptr = (interface_fn!(array_operator_index))(self.sys(), index) as *mut Variant;
-            if ptr.is_null() {
-                return None;
-            }
-            Some((*ptr).clone())
+            // The assignment drops the previous value in place
+            *self.ptr_mut(index) = value.to_variant();
+        }
+    }
+
+    /// First element, or `None` if the array is empty.
+    pub fn front(&self) -> Option<Variant> {
+        self.get(0)
+    }
+
+    /// Last element, or `None` if the array is empty.
+    pub fn back(&self) -> Option<Variant> {
+        self.len().checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    /// Appends `value` to the end of the array.
+    pub fn push<T: ToVariant>(&mut self, value: T) {
+        let value = value.to_variant();
+        unsafe { builtin_call!(array_push_back(self.sys_mut(), value.sys())) }
+    }
+
+    /// Removes and returns the last element, or `None` if the array is empty.
+    pub fn pop(&mut self) -> Option<Variant> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { builtin_call!(array_pop_back(self.sys_mut()) -> Variant) })
+    }
+
+    /// Inserts `value` at `index`, shifting all following elements back.
+    ///
+    /// # Panics
+    /// If `index > len()`.
+    pub fn insert<T: ToVariant>(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "Array insertion index {index} is out of bounds: length is {len}"
+        );
+
+        let index = index as i64;
+        let value = value.to_variant();
+        unsafe {
+            builtin_call!(array_insert(self.sys_mut(), index.sys(), value.sys()) -> i64);
+        }
+    }
+
+    /// Removes and returns the element at `index`, shifting all following elements forward.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Variant {
+        self.check_bounds(index);
+
+        let index = index as i64;
+        unsafe { builtin_call!(array_pop_at(self.sys_mut(), index.sys()) -> Variant) }
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        unsafe { builtin_call!(array_clear(self.sys_mut())) }
+    }
+
+    /// Shrinks the array to `new_len` elements, or grows it by appending nil variants.
+    pub fn resize(&mut self, new_len: usize) {
+        let new_len = new_len as i64;
+        unsafe {
+            builtin_call!(array_resize(self.sys_mut(), new_len.sys()) -> i64);
+        }
+    }
+
+    /// Returns the index of the first element equal to `value`, or `None` if there is none.
+    pub fn find<T: ToVariant>(&self, value: T) -> Option<usize> {
+        let value = value.to_variant();
+        let from = 0i64;
+        let index =
+            unsafe { builtin_call!(array_find(self.sys(), value.sys(), from.sys()) -> i64) };
+
+        // Godot returns -1 if not found
+        (index >= 0).then_some(index as usize)
+    }
+
+    /// Returns `true` if the array contains an element equal to `value`.
+    pub fn contains<T: ToVariant>(&self, value: T) -> bool {
+        let value = value.to_variant();
+        unsafe { builtin_call!(array_has(self.sys(), value.sys()) -> bool) }
+    }
+
+    /// Reverses the order of the elements in place.
+    pub fn reverse(&mut self) {
+        unsafe { builtin_call!(array_reverse(self.sys_mut())) }
+    }
+
+    /// Sorts the elements in place, using Godot's variant ordering.
+    ///
+    /// The sort is not stable; the order of elements comparing equal may change.
+    pub fn sort(&mut self) {
+        unsafe { builtin_call!(array_sort(self.sys_mut())) }
+    }
+
+    /// Returns a copy of this array, sharing nested arrays and dictionaries with the original.
+    pub fn duplicate_shallow(&self) -> Self {
+        self.duplicate(false)
+    }
+
+    /// Returns a copy of this array, recursively copying nested arrays and dictionaries.
+    pub fn duplicate_deep(&self) -> Self {
+        self.duplicate(true)
+    }
+
+    /// Returns the sub-range `begin..end` as a new array, sharing nested arrays and dictionaries with the original.
+    ///
+    /// `begin` and `end` are clamped to the array's bounds, and `end` is exclusive. With a `step` other than `Some(1)`
+    /// or `None`, only every `step`-th element is included; a negative `step` walks backwards from `begin` down to
+    /// `end`. If `end` is `None`, the slice extends to the last element, or to the first one for negative steps.
+    ///
+    /// # Panics
+    /// If `step` is `Some(0)`.
+    pub fn slice_shallow(&self, begin: usize, end: Option<usize>, step: Option<isize>) -> Self {
+        self.slice(begin, end, step, false)
+    }
+
+    /// Like [`Array::slice_shallow()`], but recursively copies nested arrays and dictionaries.
+    ///
+    /// # Panics
+    /// If `step` is `Some(0)`.
+    pub fn slice_deep(&self, begin: usize, end: Option<usize>, step: Option<isize>) -> Self {
+        self.slice(begin, end, step, true)
+    }
+
+    /// Iterates over the elements, in order.
+    pub fn iter(&self) -> ArrayIter<'_> {
+        ArrayIter {
+            array: self,
+            next_index: 0,
+        }
+    }
+
+    fn duplicate(&self, deep: bool) -> Self {
+        unsafe { builtin_call!(array_duplicate(self.sys(), deep.sys()) -> Self) }
+    }
+
+    fn slice(&self, begin: usize, end: Option<usize>, step: Option<isize>, deep: bool) -> Self {
+        let step = step.unwrap_or(1);
+        assert!(step != 0, "Array slice step must not be zero");
+
+        let len = self.len();
+        if len == 0 {
+            return Self::new();
+        }
+
+        // Godot interprets negative indices as relative to the end, so clamp before converting. Backwards, Godot reads
+        // `begin` itself, which must thus be a valid index.
+        let (begin, end_index) = if step > 0 {
+            (begin.min(len), end.map_or(len, |end| end.min(len)))
+        } else {
+            (begin.min(len - 1), end.map_or(0, |end| end.min(len - 1)))
+        };
+
+        let (begin_sys, end_sys, step_sys) = (begin as i64, end_index as i64, step as i64);
+        let mut result = unsafe {
+            builtin_call!(array_slice(self.sys(), begin_sys.sys(), end_sys.sys(), step_sys.sys(), deep.sys()) -> Self)
+        };
+
+        // An exclusive end cannot express "down to the first element", so append it if the steps reach it
+        if step < 0 && end.is_none() && begin % step.unsigned_abs() == 0 {
+            let first = self.slice(0, Some(1), None, deep);
+            result.extend(first.iter());
+        }
+
+        result
+    }
+
+    fn check_bounds(&self, index: usize) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "Array index {index} is out of bounds: length is {len}"
+        );
+    }
+
+    /// Pointer to the element at `index`, which must be in bounds.
+    unsafe fn ptr(&self, index: usize) -> *const Variant {
+        let ptr = interface_fn!(array_operator_index_const)(self.sys(), index as i64);
+        assert!(!ptr.is_null(), "Array index {index} is out of bounds");
+
+        ptr as *const Variant
+    }
+
+    /// Mutable pointer to the element at `index`, which must be in bounds.
+    unsafe fn ptr_mut(&mut self, index: usize) -> *mut Variant {
+        let ptr = interface_fn!(array_operator_index)(self.sys_mut(), index as i64);
+        assert!(!ptr.is_null(), "Array index {index} is out of bounds");
+
+        ptr as *mut Variant
+    }
+}
+
+impl GodotFfi for Array {
+    ffi_methods! {
+        type sys::GDNativeTypePtr = *mut Opaque;
+        fn sys;
+        fn write_sys;
+    }
+
+    impl_from_sys_as_copy!(from_sys => array_construct_copy);
+
+    unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+        // Can't use uninitialized pointer -- Array implementation in C++ expects that on assignment,
+        // the target type is a valid array (possibly empty)
+
+        let mut result = Self::default();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_traits_as_sys! {
+    for Array {
+        Default => array_construct_default;
+        Clone => array_construct_copy;
+        Drop => array_destroy;
+        PartialEq => array_operator_equal;
+    }
+}
+
+impl fmt::Display for Array {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
+
+impl fmt::Debug for Array {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Array({})", self.to_variant())
+    }
+}
+
+impl<'a> IntoIterator for &'a Array {
+    type Item = Variant;
+    type IntoIter = ArrayIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: ToVariant> FromIterator<T> for Array {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: ToVariant> Extend<T> for Array {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
         }
     }
 }
 
+/// Iterator over an array's elements, see [`Array::iter()`].
+pub struct ArrayIter<'a> {
+    array: &'a Array,
+    next_index: usize,
+}
+
+impl<'a> Iterator for ArrayIter<'a> {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.array.get(self.next_index)?;
+        self.next_index += 1;
+        Some(value)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed arrays
+
+/// Godot's `Array[T]` type: an [`Array`] whose element type `T` is registered with and enforced by the engine.
+///
+/// Typed arrays are reference-counted like `Array`: [`Clone`] creates a new reference to the _same_ array.
+/// Use [`TypedArray::as_untyped()`] to access the elements as variants.
 #[repr(C)]
 pub struct TypedArray<T> {
     opaque: OpaqueArray,
     _phantom: PhantomData<T>,
 }
+
 impl<T> TypedArray<T> {
     fn from_opaque(opaque: OpaqueArray) -> Self {
         Self {
@@ -67,21 +355,185 @@ impl<T> TypedArray<T> {
             _phantom: PhantomData,
         }
     }
+
+    /// Takes over the reference held by `array`, without checking its element type.
+    fn from_untyped_unchecked(array: Array) -> Self {
+        let array = std::mem::ManuallyDrop::new(array);
+        Self::from_opaque(array.opaque)
+    }
+
+    /// Views this array as an untyped [`Array`], sharing its elements.
+    pub fn as_untyped(&self) -> &Array {
+        // SAFETY: both types are #[repr(C)] wrappers around the same OpaqueArray; PhantomData is zero-sized
+        unsafe { &*(self as *const Self as *const Array) }
+    }
+
+    fn as_untyped_mut(&mut self) -> &mut Array {
+        // SAFETY: see as_untyped()
+        unsafe { &mut *(self as *mut Self as *mut Array) }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.as_untyped().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_untyped().is_empty()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.as_untyped_mut().clear()
+    }
 }
 
-impl<T> Clone for TypedArray<T> {
-    fn clone(&self) -> Self {
-        unsafe {
-            Self::from_sys_init(|opaque_ptr| {
-                let ctor = sys::method_table().array_construct_copy;
-                ctor(opaque_ptr, &self.sys() as *const sys::GDNativeTypePtr);
-            })
+impl<T: VariantMetadata + FromVariant + ToVariant> TypedArray<T> {
+    /// Creates an empty array with element type `T`.
+    pub fn new() -> Self {
+        let mut array = Array::new();
+
+        // Array[Variant] is the same as an untyped array
+        if T::variant_type() != sys::GDNATIVE_VARIANT_TYPE_NIL {
+            let variant_type = T::variant_type() as i64;
+            let class_name = StringName::from(T::class_name());
+            let script = Variant::nil();
+
+            unsafe {
+                builtin_call!(array_set_typed(
+                    array.sys_mut(),
+                    variant_type.sys(),
+                    class_name.sys(),
+                    script.sys(),
+                ));
+            }
+        }
+
+        Self::from_untyped_unchecked(array)
+    }
+
+    /// Returns the element at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.as_untyped().get(index).map(|v| Self::convert(&v))
+    }
+
+    /// Replaces the element at `index` with `value`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) {
+        self.as_untyped_mut().set(index, value)
+    }
+
+    /// First element, or `None` if the array is empty.
+    pub fn front(&self) -> Option<T> {
+        self.as_untyped().front().map(|v| Self::convert(&v))
+    }
+
+    /// Last element, or `None` if the array is empty.
+    pub fn back(&self) -> Option<T> {
+        self.as_untyped().back().map(|v| Self::convert(&v))
+    }
+
+    /// Appends `value` to the end of the array.
+    pub fn push(&mut self, value: T) {
+        self.as_untyped_mut().push(value)
+    }
+
+    /// Removes and returns the last element, or `None` if the array is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.as_untyped_mut().pop().map(|v| Self::convert(&v))
+    }
+
+    /// Inserts `value` at `index`, shifting all following elements back.
+    ///
+    /// # Panics
+    /// If `index > len()`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.as_untyped_mut().insert(index, value)
+    }
+
+    /// Removes and returns the element at `index`, shifting all following elements forward.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = self.as_untyped_mut().remove(index);
+        Self::convert(&value)
+    }
+
+    /// Iterates over the elements, in order.
+    pub fn iter(&self) -> TypedArrayIter<'_, T> {
+        TypedArrayIter {
+            inner: self.as_untyped().iter(),
+            _phantom: PhantomData,
         }
     }
+
+    /// Converts an element, whose type has already been checked by Godot.
+    fn convert(variant: &Variant) -> T {
+        T::try_from_variant(variant).unwrap_or_else(|e| {
+            panic!(
+                "element {variant:?} of typed array cannot be converted to {}: {e:?}",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    /// Godot's name of the element type, as used in `Array[T]`.
+    fn element_type_name() -> String {
+        let class_name = T::class_name();
+        if !class_name.is_empty() {
+            return class_name.to_string();
+        }
+
+        let name = unsafe {
+            GodotString::from_sys_init(|name_ptr| {
+                interface_fn!(variant_get_type_name)(
+                    T::variant_type(),
+                    name_ptr as sys::GDNativeStringPtr,
+                );
+            })
+        };
+
+        name.to_string()
+    }
 }
+
 impl<T> GodotFfi for TypedArray<T> {
-    ffi_methods! { type sys::GDNativeTypePtr = *mut Opaque; .. }
+    ffi_methods! {
+        type sys::GDNativeTypePtr = *mut Opaque;
+        fn sys;
+        fn write_sys;
+    }
+
+    unsafe fn from_sys(ptr: sys::GDNativeTypePtr) -> Self {
+        // See Array::from_sys()
+        Self::from_untyped_unchecked(Array::from_sys(ptr))
+    }
+
+    unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+        // See Array::from_sys_init(). The assignment shares the source array, and with it its element type.
+        Self::from_untyped_unchecked(Array::from_sys_init(init_fn))
+    }
+}
+
+impl<T> PtrcallMarshal for TypedArray<T> {
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_sys(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_sys(self, dst)
+    }
+}
+
+impl<T> Clone for TypedArray<T> {
+    fn clone(&self) -> Self {
+        Self::from_untyped_unchecked(self.as_untyped().clone())
+    }
 }
+
 impl<T> Drop for TypedArray<T> {
     fn drop(&mut self) {
         unsafe {
@@ -91,12 +543,158 @@ impl<T> Drop for TypedArray<T> {
     }
 }
 
-impl<T: FromVariant> TypedArray<T> {
-    pub fn get(&self, index: i64) -> Option<T> {
-        unsafe {
-            let ptr = (interface_fn!(array_operator_index))(self.sys(), index);
-            let v = Variant::from_var_sys(ptr);
-            T::try_from_variant(&v).ok()
+impl<T: VariantMetadata + FromVariant + ToVariant> Default for TypedArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PartialEq for TypedArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_untyped() == other.as_untyped()
+    }
+}
+
+impl<T> fmt::Display for TypedArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_untyped(), f)
+    }
+}
+
+impl<T> fmt::Debug for TypedArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TypedArray({})", self.as_untyped())
+    }
+}
+
+impl<'a, T: VariantMetadata + FromVariant + ToVariant> IntoIterator for &'a TypedArray<T> {
+    type Item = T;
+    type IntoIter = TypedArrayIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant> FromIterator<T> for TypedArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant> Extend<T> for TypedArray<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant> From<Vec<T>> for TypedArray<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant> From<TypedArray<T>> for Vec<T> {
+    fn from(array: TypedArray<T>) -> Self {
+        array.iter().collect()
+    }
+}
+
+/// Checks that all elements of `array` have type `T`.
+///
+/// If `array` already has the element type `T`, the result refers to the same array. Otherwise, the elements are
+/// copied into a new typed array.
+impl<T: VariantMetadata + FromVariant + ToVariant> TryFrom<&Array> for TypedArray<T> {
+    type Error = VariantConversionError;
+
+    fn try_from(array: &Array) -> Result<Self, Self::Error> {
+        let mut typed = Self::new();
+        let success =
+            unsafe { builtin_call!(array_typed_assign(typed.sys_mut(), array.sys()) -> bool) };
+
+        if success {
+            return Ok(typed);
         }
+
+        // Report the first element of the wrong type; if all types match, an object is of the wrong class
+        let expected = VariantType::from_ord(T::variant_type());
+        let error = match array.iter().find(|element| element.get_type() != expected) {
+            Some(element) => {
+                VariantConversionError::new(expected, &element, VariantConversionReason::BadType)
+            }
+            None => {
+                let element = array
+                    .iter()
+                    .find(|element| T::try_from_variant(element).is_err())
+                    .unwrap_or_default();
+                let reason = VariantConversionReason::WrongClass {
+                    expected_class: T::class_name(),
+                };
+                VariantConversionError::new(expected, &element, reason)
+            }
+        };
+        Err(error)
+    }
+}
+
+impl<T> ToVariant for TypedArray<T> {
+    fn to_variant(&self) -> Variant {
+        self.as_untyped().to_variant()
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant> FromVariant for TypedArray<T> {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        if variant.get_type() != VariantType::Array {
+            return Err(VariantConversionError::new(
+                VariantType::Array,
+                variant,
+                VariantConversionReason::BadType,
+            ));
+        }
+
+        let array = Array::try_from_variant(variant)?;
+        Self::try_from(&array)
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant> VariantMetadata for TypedArray<T> {
+    fn variant_type() -> sys::GDNativeVariantType {
+        sys::GDNATIVE_VARIANT_TYPE_ARRAY
+    }
+
+    fn property_info(property_name: &str) -> sys::GDNativePropertyInfo {
+        // Advertises the element type, so that GDScript sees e.g. Array[int] instead of Array
+        if T::variant_type() == sys::GDNATIVE_VARIANT_TYPE_NIL {
+            return Array::property_info(property_name);
+        }
+
+        let reg = unsafe { sys::get_registry() };
+        sys::GDNativePropertyInfo {
+            type_: Self::variant_type(),
+            name: reg.c_string(property_name),
+            class_name: std::ptr::null_mut(),
+            hint: PropertyHint::PROPERTY_HINT_ARRAY_TYPE.ord() as u32,
+            hint_string: reg.c_string(&Self::element_type_name()),
+            usage: 7, // Default, TODO generate global enums
+        }
+    }
+}
+
+/// Iterator over a typed array's elements, see [`TypedArray::iter()`].
+pub struct TypedArrayIter<'a, T> {
+    inner: ArrayIter<'a>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: VariantMetadata + FromVariant + ToVariant> Iterator for TypedArrayIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|v| TypedArray::<T>::convert(&v))
     }
 }
@@ -0,0 +1,364 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::f32::consts::FRAC_PI_2;
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{math, Quaternion, Vector3};
+
+type Glam = glam::f32::Mat3;
+
+/// 3x3 matrix, typically used to represent rotation and scale in 3D.
+///
+/// Stored row-major like in Godot, i.e. `rows[i]` is the i-th row. The basis vectors (X, Y, Z axes) are
+/// the _columns_, see [`Basis::column()`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Basis {
+    pub rows: [Vector3; 3],
+}
+
+impl Basis {
+    /// Identity basis, without rotation or scale.
+    pub const IDENTITY: Self = Self::from_rows(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    );
+
+    pub const fn from_rows(x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Self { rows: [x, y, z] }
+    }
+
+    /// Creates a basis from its three axis vectors `a`, `b` and `c` (Godot's `Basis(Vector3, Vector3, Vector3)`).
+    pub fn from_cols(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Self::from_glam(Glam::from_cols(a.inner(), b.inner(), c.inner()))
+    }
+
+    /// Rotation by `angle` radians around the normalized `axis`.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        Self::from_glam(Glam::from_axis_angle(axis.inner(), angle))
+    }
+
+    /// Rotation from Euler angles in radians, applied in the given `order`.
+    pub fn from_euler(order: EulerOrder, euler: Vector3) -> Self {
+        let x = Glam::from_rotation_x(euler.x());
+        let y = Glam::from_rotation_y(euler.y());
+        let z = Glam::from_rotation_z(euler.z());
+
+        let rotation = match order {
+            EulerOrder::XYZ => x * y * z,
+            EulerOrder::XZY => x * z * y,
+            EulerOrder::YXZ => y * x * z,
+            EulerOrder::YZX => y * z * x,
+            EulerOrder::ZXY => z * x * y,
+            EulerOrder::ZYX => z * y * x,
+        };
+
+        Self::from_glam(rotation)
+    }
+
+    /// Rotation represented by a normalized quaternion.
+    pub fn from_quaternion(quaternion: Quaternion) -> Self {
+        Self::from_glam(Glam::from_quat(quaternion.inner()))
+    }
+
+    /// Scale matrix with the components of `scale` on the diagonal.
+    pub fn from_scale(scale: Vector3) -> Self {
+        Self::from_glam(Glam::from_diagonal(scale.inner()))
+    }
+
+    /// Rotation whose forward axis (-Z) points towards `target`, with the Y axis as close to `up` as possible.
+    ///
+    /// Does not panic if `target` or `up` is zero, or if they are parallel: the result has NaN components instead.
+    /// Godot only reports an error in these cases.
+    pub fn looking_at(target: Vector3, up: Vector3) -> Self {
+        // Divide by the lengths instead of normalized(), which would silently turn degenerate vectors into zero
+        let z = -target / target.length();
+        let x = up.cross(z);
+        let x = x / x.length();
+        let y = z.cross(x);
+        Self::from_cols(x, y, z)
+    }
+
+    /// Returns the `index`-th column, i.e. the X, Y or Z axis of this basis.
+    ///
+    /// # Panics
+    /// If `index` is not in `0..3`.
+    pub fn column(self, index: usize) -> Vector3 {
+        Vector3::from_inner(self.to_glam().col(index))
+    }
+
+    /// Replaces the `index`-th column.
+    ///
+    /// # Panics
+    /// If `index` is not in `0..3`.
+    pub fn set_column(&mut self, index: usize, column: Vector3) {
+        let mut glam = self.to_glam();
+        *glam.col_mut(index) = column.inner();
+        *self = Self::from_glam(glam);
+    }
+
+    pub fn determinant(self) -> f32 {
+        self.to_glam().determinant()
+    }
+
+    /// Inverse matrix.
+    ///
+    /// Does not panic if the basis is singular (determinant zero): the result has non-finite components instead.
+    /// Godot only reports an error in this case.
+    pub fn inverse(self) -> Self {
+        // Not glam's inverse(), which asserts a non-zero determinant in debug builds
+        let (x, y, z) = (self.column(0), self.column(1), self.column(2));
+        let inv_det = 1.0 / z.dot(x.cross(y));
+
+        Self::from_cols(
+            y.cross(z) * inv_det,
+            z.cross(x) * inv_det,
+            x.cross(y) * inv_det,
+        )
+        .transposed()
+    }
+
+    pub fn transposed(self) -> Self {
+        Self::from_glam(self.to_glam().transpose())
+    }
+
+    /// Returns the basis with orthogonal, normalized axes (Gram-Schmidt), which removes any scale or skew.
+    pub fn orthonormalized(self) -> Self {
+        let x = self.column(0).normalized();
+        let y = self.column(1);
+        let y = (y - x * x.dot(y)).normalized();
+        let z = self.column(2);
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalized();
+
+        Self::from_cols(x, y, z)
+    }
+
+    /// Returns the basis scaled by `scale` along the global axes, i.e. `Basis::from_scale(scale) * self`.
+    pub fn scaled(self, scale: Vector3) -> Self {
+        let [x, y, z] = self.rows;
+        Self::from_rows(x * scale.x(), y * scale.y(), z * scale.z())
+    }
+
+    /// Returns the basis rotated by `angle` radians around the normalized global `axis`.
+    pub fn rotated(self, axis: Vector3, angle: f32) -> Self {
+        Self::from_axis_angle(axis, angle) * self
+    }
+
+    /// Length of each axis, negated if the basis contains a reflection.
+    pub fn get_scale(self) -> Vector3 {
+        let sign = math::sign(self.determinant());
+        let scale = Vector3::new(
+            self.column(0).length(),
+            self.column(1).length(),
+            self.column(2).length(),
+        );
+
+        scale * sign
+    }
+
+    /// Rotation part of this basis, ignoring scale.
+    pub fn get_rotation_quaternion(self) -> Quaternion {
+        let mut orthonormal = self.orthonormalized();
+        if orthonormal.determinant() < 0.0 {
+            orthonormal = orthonormal.scaled(-Vector3::ONE);
+        }
+
+        Quaternion::from_basis(orthonormal)
+    }
+
+    /// Euler angles in radians for the given `order`. Assumes that the basis is a pure rotation.
+    ///
+    /// The result is such that `Basis::from_euler(order, basis.get_euler(order))` reproduces `basis`.
+    pub fn get_euler(self, order: EulerOrder) -> Vector3 {
+        let m = |row: usize, col: usize| match col {
+            0 => self.rows[row].x(),
+            1 => self.rows[row].y(),
+            _ => self.rows[row].z(),
+        };
+        let limit = 1.0 - math::CMP_EPSILON;
+
+        // Matrix layouts for each order are documented in Godot's core/math/basis.cpp.
+        match order {
+            EulerOrder::XYZ => {
+                let sy = m(0, 2);
+                if sy >= limit {
+                    Vector3::new(m(2, 1).atan2(m(1, 1)), FRAC_PI_2, 0.0)
+                } else if sy <= -limit {
+                    Vector3::new(m(2, 1).atan2(m(1, 1)), -FRAC_PI_2, 0.0)
+                } else if m(1, 0) == 0.0
+                    && m(0, 1) == 0.0
+                    && m(1, 2) == 0.0
+                    && m(2, 1) == 0.0
+                    && m(1, 1) == 1.0
+                {
+                    // Pure Y rotation, return the simplest form
+                    Vector3::new(0.0, m(0, 2).atan2(m(0, 0)), 0.0)
+                } else {
+                    Vector3::new(
+                        (-m(1, 2)).atan2(m(2, 2)),
+                        sy.asin(),
+                        (-m(0, 1)).atan2(m(0, 0)),
+                    )
+                }
+            }
+            EulerOrder::XZY => {
+                let sz = m(0, 1);
+                if sz >= limit {
+                    Vector3::new(-m(1, 2).atan2(m(2, 2)), 0.0, -FRAC_PI_2)
+                } else if sz <= -limit {
+                    Vector3::new(-m(1, 2).atan2(m(2, 2)), 0.0, FRAC_PI_2)
+                } else {
+                    Vector3::new(m(2, 1).atan2(m(1, 1)), m(0, 2).atan2(m(0, 0)), (-sz).asin())
+                }
+            }
+            EulerOrder::YXZ => {
+                let m12 = m(1, 2);
+                if m12 >= limit {
+                    Vector3::new(-FRAC_PI_2, -m(0, 1).atan2(m(0, 0)), 0.0)
+                } else if m12 <= -limit {
+                    Vector3::new(FRAC_PI_2, m(0, 1).atan2(m(0, 0)), 0.0)
+                } else if m(1, 0) == 0.0
+                    && m(0, 1) == 0.0
+                    && m(0, 2) == 0.0
+                    && m(2, 0) == 0.0
+                    && m(0, 0) == 1.0
+                {
+                    // Pure X rotation, return the simplest form
+                    Vector3::new((-m12).atan2(m(1, 1)), 0.0, 0.0)
+                } else {
+                    Vector3::new(
+                        (-m12).asin(),
+                        m(0, 2).atan2(m(2, 2)),
+                        m(1, 0).atan2(m(1, 1)),
+                    )
+                }
+            }
+            EulerOrder::YZX => {
+                let sz = m(1, 0);
+                if sz >= limit {
+                    Vector3::new(m(2, 1).atan2(m(2, 2)), 0.0, FRAC_PI_2)
+                } else if sz <= -limit {
+                    Vector3::new(m(2, 1).atan2(m(2, 2)), 0.0, -FRAC_PI_2)
+                } else {
+                    Vector3::new(
+                        (-m(1, 2)).atan2(m(1, 1)),
+                        (-m(2, 0)).atan2(m(0, 0)),
+                        sz.asin(),
+                    )
+                }
+            }
+            EulerOrder::ZXY => {
+                let sx = m(2, 1);
+                if sx >= limit {
+                    Vector3::new(FRAC_PI_2, m(0, 2).atan2(m(0, 0)), 0.0)
+                } else if sx <= -limit {
+                    Vector3::new(-FRAC_PI_2, m(0, 2).atan2(m(0, 0)), 0.0)
+                } else {
+                    Vector3::new(
+                        sx.asin(),
+                        (-m(2, 0)).atan2(m(2, 2)),
+                        (-m(0, 1)).atan2(m(1, 1)),
+                    )
+                }
+            }
+            EulerOrder::ZYX => {
+                let sy = m(2, 0);
+                if sy >= limit {
+                    Vector3::new(0.0, -FRAC_PI_2, -m(0, 1).atan2(m(1, 1)))
+                } else if sy <= -limit {
+                    Vector3::new(0.0, FRAC_PI_2, -m(0, 1).atan2(m(1, 1)))
+                } else {
+                    Vector3::new(m(2, 1).atan2(m(2, 2)), (-sy).asin(), m(1, 0).atan2(m(0, 0)))
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if all rows are approximately equal, see [`Vector3::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(a, b)| a.is_equal_approx(*b))
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.rows.iter().all(|row| row.is_finite())
+    }
+
+    /// Converts to the equivalent glam matrix, which is stored column-major.
+    pub(crate) fn to_glam(self) -> Glam {
+        let [x, y, z] = self.rows;
+        Glam::from_cols(x.inner(), y.inner(), z.inner()).transpose()
+    }
+
+    pub(crate) fn from_glam(glam: Glam) -> Self {
+        let transposed = glam.transpose();
+        Self::from_rows(
+            Vector3::from_inner(transposed.x_axis),
+            Vector3::from_inner(transposed.y_axis),
+            Vector3::from_inner(transposed.z_axis),
+        )
+    }
+}
+
+impl Default for Basis {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl std::ops::Mul for Basis {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_glam(self.to_glam() * rhs.to_glam())
+    }
+}
+
+impl std::ops::MulAssign for Basis {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Transforms a vector by the matrix.
+impl std::ops::Mul<Vector3> for Basis {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        let [x, y, z] = self.rows;
+        Vector3::new(x.dot(rhs), y.dot(rhs), z.dot(rhs))
+    }
+}
+
+impl GodotFfi for Basis {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// TODO auto-generate this, alongside all the other builtin type's enums
+
+/// Order in which Euler angle rotations are applied. Godot's default is [`EulerOrder::YXZ`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl GodotFfi for EulerOrder {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
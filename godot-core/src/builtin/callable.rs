@@ -0,0 +1,152 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use godot_ffi as sys;
+use sys::types::OpaqueCallable;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Array, FromVariant, StringName, ToVariant, Variant};
+use crate::engine::Object;
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// A reference to a method on an object, which can be invoked later.
+///
+/// Callables are typically received from GDScript (e.g. as `#[func]` parameters or signal connections), or created
+/// with [`Callable::from_object_method()`].
+#[repr(C)]
+pub struct Callable {
+    opaque: OpaqueCallable,
+}
+
+impl Callable {
+    /// Creates a callable for the method named `method` on `object`.
+    pub fn from_object_method<T, S>(object: Gd<T>, method: S) -> Self
+    where
+        T: GodotClass, // + Inherits<Object>,
+        S: Into<StringName>,
+    {
+        // upcast not needed
+        let method = method.into();
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::method_table().callable_from_object_method;
+                let args = [object.sys(), method.sys()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+
+    // TODO Callable::from_fn() for Rust closures. This requires custom callables (C++ CallableCustom) with hooks
+    // for call, equality, hash and destruction, which gdnative_interface.h does not expose yet. Routing through a
+    // helper object is not an alternative: a Callable only stores the object's ID, so it would not keep the closure
+    // alive, and nothing would notify us when Godot drops the last Callable.
+
+    /// Creates an invalid callable, which does not refer to any method.
+    pub fn invalid() -> Self {
+        Self::default()
+    }
+
+    /// Calls the method with the given arguments and returns its result.
+    ///
+    /// If the callable is invalid, Godot prints an error and nil is returned.
+    pub fn call(&self, args: &[Variant]) -> Variant {
+        let args: Array = args.iter().cloned().collect();
+        unsafe { builtin_call!(callable_callv(self.sys(), args.sys()) -> Variant) }
+    }
+
+    /// Calls the method with the given arguments at the end of the current frame (during idle time).
+    pub fn call_deferred(&self, args: &[Variant]) {
+        self.to_variant().call_panicking("call_deferred", args);
+    }
+
+    /// Returns a callable which appends `args` to the arguments of each call.
+    pub fn bind(&self, args: &[Variant]) -> Callable {
+        let bound = self.to_variant().call_panicking("bind", args);
+        Callable::from_variant(&bound)
+    }
+
+    /// Returns a callable which drops the last `arg_count` arguments of each call.
+    pub fn unbind(&self, arg_count: usize) -> Callable {
+        let arg_count = arg_count as i64;
+        unsafe { builtin_call!(callable_unbind(self.sys(), arg_count.sys()) -> Callable) }
+    }
+
+    /// Returns `true` if the target object is alive and has the method (or if this is a valid custom callable).
+    pub fn is_valid(&self) -> bool {
+        unsafe { builtin_call!(callable_is_valid(self.sys()) -> bool) }
+    }
+
+    /// Returns `true` if this callable has no target, e.g. if it was default-constructed.
+    pub fn is_null(&self) -> bool {
+        unsafe { builtin_call!(callable_is_null(self.sys()) -> bool) }
+    }
+
+    /// Returns the object the method is called on, or `None` if there is none or it has been freed.
+    pub fn get_object(&self) -> Option<Gd<Object>> {
+        self.get_object_id().and_then(Gd::try_from_instance_id)
+    }
+
+    /// Returns the instance ID of the target object, or `None` if there is none.
+    pub fn get_object_id(&self) -> Option<InstanceId> {
+        let id = unsafe { builtin_call!(callable_get_object_id(self.sys()) -> i64) };
+        InstanceId::try_from_i64(id)
+    }
+
+    /// Name of the method that is called. Empty for custom callables.
+    pub fn get_method(&self) -> StringName {
+        unsafe { builtin_call!(callable_get_method(self.sys()) -> StringName) }
+    }
+}
+
+impl GodotFfi for Callable {
+    ffi_methods! {
+        type sys::GDNativeTypePtr = *mut Opaque;
+        fn sys;
+        fn write_sys;
+    }
+
+    impl_from_sys_as_copy!(from_sys => callable_construct_copy);
+
+    unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+        // Can't use uninitialized pointer -- Callable implementation in C++ expects that on assignment,
+        // the target type is a valid callable (possibly null)
+
+        let mut result = Self::default();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_traits_as_sys! {
+    for Callable {
+        Default => callable_construct_default;
+        Clone => callable_construct_copy;
+        Drop => callable_destroy;
+        Eq => callable_operator_equal;
+    }
+}
+
+impl Hash for Callable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash = unsafe { builtin_call!(callable_hash(self.sys()) -> i64) };
+        hash.hash(state)
+    }
+}
+
+impl fmt::Display for Callable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
+
+impl fmt::Debug for Callable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Callable({})", self.to_variant())
+    }
+}
@@ -7,6 +7,12 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
+use crate::builtin::math;
+
+/// Color in RGBA format, with components usually in the range `0.0..=1.0`.
+///
+/// Components above 1.0 are allowed for overbright (HDR) colors. Named colors are available as
+/// associated constants, e.g. [`Color::CORNFLOWER_BLUE`].
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Color {
@@ -17,10 +23,254 @@ pub struct Color {
 }
 
 impl Color {
-    #[allow(dead_code)]
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
+
+    /// Creates a color from 8-bit components, where 255 maps to 1.0.
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(from_u8(r), from_u8(g), from_u8(b), from_u8(a))
+    }
+
+    /// Creates a color from a 32-bit integer in RGBA order, e.g. `0xff8000ff` for opaque orange.
+    pub fn from_rgba32(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::from_rgba8(r, g, b, a)
+    }
+
+    /// Creates a color from HSV values, all in the range `0.0..=1.0`. Hue wraps around.
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Self {
+        if s == 0.0 {
+            return Self::new(v, v, v, a);
+        }
+
+        let h = (h * 6.0).rem_euclid(6.0);
+        let f = h.fract();
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+
+        // Sector 6 can only result from rounding and is equivalent to 0
+        match h as u8 {
+            1 => Self::new(q, v, p, a),
+            2 => Self::new(p, v, t, a),
+            3 => Self::new(p, q, v, a),
+            4 => Self::new(t, p, v, a),
+            5 => Self::new(v, p, q, a),
+            _ => Self::new(v, t, p, a),
+        }
+    }
+
+    /// Parses an HTML color code `RRGGBB`, `RRGGBBAA`, `RGB` or `RGBA` (hexadecimal, case-insensitive),
+    /// optionally prefixed with `#`.
+    ///
+    /// Returns `None` if `html` is not a valid color code.
+    pub fn from_html(html: &str) -> Option<Self> {
+        let html = html.strip_prefix('#').unwrap_or(html);
+        let digits = html
+            .chars()
+            .map(|c| c.to_digit(16))
+            .collect::<Option<Vec<u32>>>()?;
+
+        let component = |i: usize| match digits.len() {
+            3 | 4 => digits[i] as f32 / 15.0,
+            _ => (digits[2 * i] * 16 + digits[2 * i + 1]) as f32 / 255.0,
+        };
+
+        match digits.len() {
+            3 | 6 => Some(Self::new(component(0), component(1), component(2), 1.0)),
+            4 | 8 => Some(Self::new(
+                component(0),
+                component(1),
+                component(2),
+                component(3),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTML color code `rrggbbaa` (or `rrggbb` if `with_alpha` is `false`), in lowercase and without `#`.
+    ///
+    /// Components are clamped to `0.0..=1.0`.
+    pub fn to_html(self, with_alpha: bool) -> String {
+        let [r, g, b, a] = self.to_rgba32().to_be_bytes();
+        if with_alpha {
+            format!("{r:02x}{g:02x}{b:02x}{a:02x}")
+        } else {
+            format!("{r:02x}{g:02x}{b:02x}")
+        }
+    }
+
+    /// Packs the color into a 32-bit integer in RGBA order, 8 bits per component.
+    ///
+    /// Components are clamped to `0.0..=1.0`.
+    pub fn to_rgba32(self) -> u32 {
+        u32::from_be_bytes([to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)])
+    }
+
+    /// Packs the color into a 32-bit integer in ARGB order, 8 bits per component.
+    ///
+    /// Components are clamped to `0.0..=1.0`.
+    pub fn to_argb32(self) -> u32 {
+        u32::from_be_bytes([to_u8(self.a), to_u8(self.r), to_u8(self.g), to_u8(self.b)])
+    }
+
+    /// Packs the color into a 32-bit integer in ABGR order, 8 bits per component.
+    ///
+    /// Components are clamped to `0.0..=1.0`.
+    pub fn to_abgr32(self) -> u32 {
+        u32::from_be_bytes([to_u8(self.a), to_u8(self.b), to_u8(self.g), to_u8(self.r)])
+    }
+
+    /// HSV hue in the range `0.0..1.0`.
+    pub fn h(self) -> f32 {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        let h = if self.r == max {
+            (self.g - self.b) / delta
+        } else if self.g == max {
+            2.0 + (self.b - self.r) / delta
+        } else {
+            4.0 + (self.r - self.g) / delta
+        };
+
+        let h = h / 6.0;
+        if h < 0.0 {
+            h + 1.0
+        } else {
+            h
+        }
+    }
+
+    /// HSV saturation in the range `0.0..=1.0`.
+    pub fn s(self) -> f32 {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        if max == 0.0 {
+            0.0
+        } else {
+            (max - min) / max
+        }
+    }
+
+    /// HSV value (brightness), i.e. the largest RGB component.
+    pub fn v(self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
+    /// Creates a color from OKHSL values, all in the range `0.0..=1.0`. Hue wraps around.
+    ///
+    /// OKHSL is a perceptual color space based on Oklab: in contrast to HSV, colors of the same lightness `l` appear
+    /// equally bright. The resulting components are clamped to `0.0..=1.0`.
+    pub fn from_ok_hsl(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let (r, g, b) = ok_hsl::to_srgb(h, s, l);
+        Self::new(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), a)
+    }
+
+    /// OKHSL hue in the range `0.0..=1.0`.
+    pub fn ok_hsl_h(self) -> f32 {
+        ok_hsl::component(ok_hsl::from_srgb(self).0)
+    }
+
+    /// OKHSL saturation in the range `0.0..=1.0`.
+    pub fn ok_hsl_s(self) -> f32 {
+        ok_hsl::component(ok_hsl::from_srgb(self).1)
+    }
+
+    /// OKHSL lightness in the range `0.0..=1.0`.
+    pub fn ok_hsl_l(self) -> f32 {
+        ok_hsl::component(ok_hsl::from_srgb(self).2)
+    }
+
+    /// Linear interpolation of all components, including alpha, towards `to`.
+    pub fn lerp(self, to: Self, weight: f32) -> Self {
+        Self::new(
+            math::lerp(self.r, to.r, weight),
+            math::lerp(self.g, to.g, weight),
+            math::lerp(self.b, to.b, weight),
+            math::lerp(self.a, to.a, weight),
+        )
+    }
+
+    /// Alpha-blends `over` on top of this color, as if painting it over `self`.
+    pub fn blend(self, over: Self) -> Self {
+        let inv_alpha = 1.0 - over.a;
+        let a = self.a * inv_alpha + over.a;
+        if a == 0.0 {
+            return Self::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        let mix = |dst: f32, src: f32| (dst * self.a * inv_alpha + src * over.a) / a;
+        Self::new(
+            mix(self.r, over.r),
+            mix(self.g, over.g),
+            mix(self.b, over.b),
+            a,
+        )
+    }
+
+    /// Moves the RGB components towards white by `amount` (0.0 to 1.0). Alpha is unchanged.
+    pub fn lightened(self, amount: f32) -> Self {
+        let lighten = |c: f32| c + (1.0 - c) * amount;
+        Self::new(lighten(self.r), lighten(self.g), lighten(self.b), self.a)
+    }
+
+    /// Moves the RGB components towards black by `amount` (0.0 to 1.0). Alpha is unchanged.
+    pub fn darkened(self, amount: f32) -> Self {
+        let darken = |c: f32| c * (1.0 - amount);
+        Self::new(darken(self.r), darken(self.g), darken(self.b), self.a)
+    }
+
+    /// Inverts the RGB components. Alpha is unchanged.
+    pub fn inverted(self) -> Self {
+        Self::new(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a)
+    }
+
+    /// Converts from linear color space to sRGB (gamma-encoded). Alpha is unchanged.
+    pub fn linear_to_srgb(self) -> Self {
+        let convert = |c: f32| {
+            if c < 0.0031308 {
+                12.92 * c
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+        Self::new(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Converts from sRGB (gamma-encoded) to linear color space. Alpha is unchanged.
+    pub fn srgb_to_linear(self) -> Self {
+        let convert = |c: f32| {
+            if c < 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        Self::new(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Relative luminance in the range `0.0..=1.0`, assuming the color is in linear space.
+    pub fn luminance(self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Returns `true` if all components are approximately equal, see [`math::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        math::is_equal_approx(self.r, other.r)
+            && math::is_equal_approx(self.g, other.g)
+            && math::is_equal_approx(self.b, other.b)
+            && math::is_equal_approx(self.a, other.a)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.r.is_finite() && self.g.is_finite() && self.b.is_finite() && self.a.is_finite()
+    }
 }
 
 impl GodotFfi for Color {
@@ -29,6 +279,485 @@ impl GodotFfi for Color {
 
 impl Default for Color {
     fn default() -> Self {
-        Self::new(0.0, 0.0, 0.0, 1.0)
+        Self::BLACK
+    }
+}
+
+fn from_u8(component: u8) -> f32 {
+    component as f32 / 255.0
+}
+
+fn to_u8(component: f32) -> u8 {
+    // Float-to-int casts saturate, so out-of-range values are clamped
+    (component * 255.0).round() as u8
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Named colors
+
+// TODO auto-generate this, alongside all the other builtin type's constants
+/// Named colors, identical to the ones in Godot (mostly X11 colors).
+///
+/// Where X11 and web colors differ, the web variant has a `WEB_` prefix, e.g. [`Color::WEB_GREEN`].
+impl Color {
+    pub const ALICE_BLUE: Self = Self::new(0.9411765, 0.972549, 1.0, 1.0);
+    pub const ANTIQUE_WHITE: Self = Self::new(0.98039216, 0.92156863, 0.84313726, 1.0);
+    pub const AQUA: Self = Self::new(0.0, 1.0, 1.0, 1.0);
+    pub const AQUAMARINE: Self = Self::new(0.49803922, 1.0, 0.83137256, 1.0);
+    pub const AZURE: Self = Self::new(0.9411765, 1.0, 1.0, 1.0);
+    pub const BEIGE: Self = Self::new(0.9607843, 0.9607843, 0.8627451, 1.0);
+    pub const BISQUE: Self = Self::new(1.0, 0.89411765, 0.76862746, 1.0);
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const BLANCHED_ALMOND: Self = Self::new(1.0, 0.92156863, 0.8039216, 1.0);
+    pub const BLUE: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+    pub const BLUE_VIOLET: Self = Self::new(0.5411765, 0.16862746, 0.8862745, 1.0);
+    pub const BROWN: Self = Self::new(0.64705884, 0.16470589, 0.16470589, 1.0);
+    pub const BURLYWOOD: Self = Self::new(0.87058824, 0.72156864, 0.5294118, 1.0);
+    pub const CADET_BLUE: Self = Self::new(0.37254903, 0.61960787, 0.627451, 1.0);
+    pub const CHARTREUSE: Self = Self::new(0.49803922, 1.0, 0.0, 1.0);
+    pub const CHOCOLATE: Self = Self::new(0.8235294, 0.4117647, 0.11764706, 1.0);
+    pub const CORAL: Self = Self::new(1.0, 0.49803922, 0.3137255, 1.0);
+    pub const CORNFLOWER_BLUE: Self = Self::new(0.39215687, 0.58431375, 0.92941177, 1.0);
+    pub const CORNSILK: Self = Self::new(1.0, 0.972549, 0.8627451, 1.0);
+    pub const CRIMSON: Self = Self::new(0.8627451, 0.078431375, 0.23529412, 1.0);
+    pub const CYAN: Self = Self::new(0.0, 1.0, 1.0, 1.0);
+    pub const DARK_BLUE: Self = Self::new(0.0, 0.0, 0.54509807, 1.0);
+    pub const DARK_CYAN: Self = Self::new(0.0, 0.54509807, 0.54509807, 1.0);
+    pub const DARK_GOLDENROD: Self = Self::new(0.72156864, 0.5254902, 0.043137256, 1.0);
+    pub const DARK_GRAY: Self = Self::new(0.6627451, 0.6627451, 0.6627451, 1.0);
+    pub const DARK_GREEN: Self = Self::new(0.0, 0.39215687, 0.0, 1.0);
+    pub const DARK_KHAKI: Self = Self::new(0.7411765, 0.7176471, 0.41960785, 1.0);
+    pub const DARK_MAGENTA: Self = Self::new(0.54509807, 0.0, 0.54509807, 1.0);
+    pub const DARK_OLIVE_GREEN: Self = Self::new(0.33333334, 0.41960785, 0.18431373, 1.0);
+    pub const DARK_ORANGE: Self = Self::new(1.0, 0.54901963, 0.0, 1.0);
+    pub const DARK_ORCHID: Self = Self::new(0.6, 0.19607843, 0.8, 1.0);
+    pub const DARK_RED: Self = Self::new(0.54509807, 0.0, 0.0, 1.0);
+    pub const DARK_SALMON: Self = Self::new(0.9137255, 0.5882353, 0.47843137, 1.0);
+    pub const DARK_SEA_GREEN: Self = Self::new(0.56078434, 0.7372549, 0.56078434, 1.0);
+    pub const DARK_SLATE_BLUE: Self = Self::new(0.28235295, 0.23921569, 0.54509807, 1.0);
+    pub const DARK_SLATE_GRAY: Self = Self::new(0.18431373, 0.30980393, 0.30980393, 1.0);
+    pub const DARK_TURQUOISE: Self = Self::new(0.0, 0.80784315, 0.81960785, 1.0);
+    pub const DARK_VIOLET: Self = Self::new(0.5803922, 0.0, 0.827451, 1.0);
+    pub const DEEP_PINK: Self = Self::new(1.0, 0.078431375, 0.5764706, 1.0);
+    pub const DEEP_SKY_BLUE: Self = Self::new(0.0, 0.7490196, 1.0, 1.0);
+    pub const DIM_GRAY: Self = Self::new(0.4117647, 0.4117647, 0.4117647, 1.0);
+    pub const DODGER_BLUE: Self = Self::new(0.11764706, 0.5647059, 1.0, 1.0);
+    pub const FIREBRICK: Self = Self::new(0.69803923, 0.13333334, 0.13333334, 1.0);
+    pub const FLORAL_WHITE: Self = Self::new(1.0, 0.98039216, 0.9411765, 1.0);
+    pub const FOREST_GREEN: Self = Self::new(0.13333334, 0.54509807, 0.13333334, 1.0);
+    pub const FUCHSIA: Self = Self::new(1.0, 0.0, 1.0, 1.0);
+    pub const GAINSBORO: Self = Self::new(0.8627451, 0.8627451, 0.8627451, 1.0);
+    pub const GHOST_WHITE: Self = Self::new(0.972549, 0.972549, 1.0, 1.0);
+    pub const GOLD: Self = Self::new(1.0, 0.84313726, 0.0, 1.0);
+    pub const GOLDENROD: Self = Self::new(0.85490197, 0.64705884, 0.1254902, 1.0);
+    pub const GRAY: Self = Self::new(0.74509805, 0.74509805, 0.74509805, 1.0);
+    pub const GREEN: Self = Self::new(0.0, 1.0, 0.0, 1.0);
+    pub const GREEN_YELLOW: Self = Self::new(0.6784314, 1.0, 0.18431373, 1.0);
+    pub const HONEYDEW: Self = Self::new(0.9411765, 1.0, 0.9411765, 1.0);
+    pub const HOT_PINK: Self = Self::new(1.0, 0.4117647, 0.7058824, 1.0);
+    pub const INDIAN_RED: Self = Self::new(0.8039216, 0.36078432, 0.36078432, 1.0);
+    pub const INDIGO: Self = Self::new(0.29411766, 0.0, 0.50980395, 1.0);
+    pub const IVORY: Self = Self::new(1.0, 1.0, 0.9411765, 1.0);
+    pub const KHAKI: Self = Self::new(0.9411765, 0.9019608, 0.54901963, 1.0);
+    pub const LAVENDER: Self = Self::new(0.9019608, 0.9019608, 0.98039216, 1.0);
+    pub const LAVENDER_BLUSH: Self = Self::new(1.0, 0.9411765, 0.9607843, 1.0);
+    pub const LAWN_GREEN: Self = Self::new(0.4862745, 0.9882353, 0.0, 1.0);
+    pub const LEMON_CHIFFON: Self = Self::new(1.0, 0.98039216, 0.8039216, 1.0);
+    pub const LIGHT_BLUE: Self = Self::new(0.6784314, 0.84705883, 0.9019608, 1.0);
+    pub const LIGHT_CORAL: Self = Self::new(0.9411765, 0.5019608, 0.5019608, 1.0);
+    pub const LIGHT_CYAN: Self = Self::new(0.8784314, 1.0, 1.0, 1.0);
+    pub const LIGHT_GOLDENROD: Self = Self::new(0.98039216, 0.98039216, 0.8235294, 1.0);
+    pub const LIGHT_GRAY: Self = Self::new(0.827451, 0.827451, 0.827451, 1.0);
+    pub const LIGHT_GREEN: Self = Self::new(0.5647059, 0.93333334, 0.5647059, 1.0);
+    pub const LIGHT_PINK: Self = Self::new(1.0, 0.7137255, 0.75686276, 1.0);
+    pub const LIGHT_SALMON: Self = Self::new(1.0, 0.627451, 0.47843137, 1.0);
+    pub const LIGHT_SEA_GREEN: Self = Self::new(0.1254902, 0.69803923, 0.6666667, 1.0);
+    pub const LIGHT_SKY_BLUE: Self = Self::new(0.5294118, 0.80784315, 0.98039216, 1.0);
+    pub const LIGHT_SLATE_GRAY: Self = Self::new(0.46666667, 0.53333336, 0.6, 1.0);
+    pub const LIGHT_STEEL_BLUE: Self = Self::new(0.6901961, 0.76862746, 0.87058824, 1.0);
+    pub const LIGHT_YELLOW: Self = Self::new(1.0, 1.0, 0.8784314, 1.0);
+    pub const LIME: Self = Self::new(0.0, 1.0, 0.0, 1.0);
+    pub const LIME_GREEN: Self = Self::new(0.19607843, 0.8039216, 0.19607843, 1.0);
+    pub const LINEN: Self = Self::new(0.98039216, 0.9411765, 0.9019608, 1.0);
+    pub const MAGENTA: Self = Self::new(1.0, 0.0, 1.0, 1.0);
+    pub const MAROON: Self = Self::new(0.6901961, 0.1882353, 0.3764706, 1.0);
+    pub const MEDIUM_AQUAMARINE: Self = Self::new(0.4, 0.8039216, 0.6666667, 1.0);
+    pub const MEDIUM_BLUE: Self = Self::new(0.0, 0.0, 0.8039216, 1.0);
+    pub const MEDIUM_ORCHID: Self = Self::new(0.7294118, 0.33333334, 0.827451, 1.0);
+    pub const MEDIUM_PURPLE: Self = Self::new(0.5764706, 0.4392157, 0.85882354, 1.0);
+    pub const MEDIUM_SEA_GREEN: Self = Self::new(0.23529412, 0.7019608, 0.44313726, 1.0);
+    pub const MEDIUM_SLATE_BLUE: Self = Self::new(0.48235294, 0.40784314, 0.93333334, 1.0);
+    pub const MEDIUM_SPRING_GREEN: Self = Self::new(0.0, 0.98039216, 0.6039216, 1.0);
+    pub const MEDIUM_TURQUOISE: Self = Self::new(0.28235295, 0.81960785, 0.8, 1.0);
+    pub const MEDIUM_VIOLET_RED: Self = Self::new(0.78039217, 0.08235294, 0.52156866, 1.0);
+    pub const MIDNIGHT_BLUE: Self = Self::new(0.09803922, 0.09803922, 0.4392157, 1.0);
+    pub const MINT_CREAM: Self = Self::new(0.9607843, 1.0, 0.98039216, 1.0);
+    pub const MISTY_ROSE: Self = Self::new(1.0, 0.89411765, 0.88235295, 1.0);
+    pub const MOCCASIN: Self = Self::new(1.0, 0.89411765, 0.70980394, 1.0);
+    pub const NAVAJO_WHITE: Self = Self::new(1.0, 0.87058824, 0.6784314, 1.0);
+    pub const NAVY_BLUE: Self = Self::new(0.0, 0.0, 0.5019608, 1.0);
+    pub const OLD_LACE: Self = Self::new(0.99215686, 0.9607843, 0.9019608, 1.0);
+    pub const OLIVE: Self = Self::new(0.5019608, 0.5019608, 0.0, 1.0);
+    pub const OLIVE_DRAB: Self = Self::new(0.41960785, 0.5568628, 0.13725491, 1.0);
+    pub const ORANGE: Self = Self::new(1.0, 0.64705884, 0.0, 1.0);
+    pub const ORANGE_RED: Self = Self::new(1.0, 0.27058825, 0.0, 1.0);
+    pub const ORCHID: Self = Self::new(0.85490197, 0.4392157, 0.8392157, 1.0);
+    pub const PALE_GOLDENROD: Self = Self::new(0.93333334, 0.9098039, 0.6666667, 1.0);
+    pub const PALE_GREEN: Self = Self::new(0.59607846, 0.9843137, 0.59607846, 1.0);
+    pub const PALE_TURQUOISE: Self = Self::new(0.6862745, 0.93333334, 0.93333334, 1.0);
+    pub const PALE_VIOLET_RED: Self = Self::new(0.85882354, 0.4392157, 0.5764706, 1.0);
+    pub const PAPAYA_WHIP: Self = Self::new(1.0, 0.9372549, 0.8352941, 1.0);
+    pub const PEACH_PUFF: Self = Self::new(1.0, 0.85490197, 0.7254902, 1.0);
+    pub const PERU: Self = Self::new(0.8039216, 0.52156866, 0.24705882, 1.0);
+    pub const PINK: Self = Self::new(1.0, 0.7529412, 0.79607844, 1.0);
+    pub const PLUM: Self = Self::new(0.8666667, 0.627451, 0.8666667, 1.0);
+    pub const POWDER_BLUE: Self = Self::new(0.6901961, 0.8784314, 0.9019608, 1.0);
+    pub const PURPLE: Self = Self::new(0.627451, 0.1254902, 0.9411765, 1.0);
+    pub const REBECCA_PURPLE: Self = Self::new(0.4, 0.2, 0.6, 1.0);
+    pub const RED: Self = Self::new(1.0, 0.0, 0.0, 1.0);
+    pub const ROSY_BROWN: Self = Self::new(0.7372549, 0.56078434, 0.56078434, 1.0);
+    pub const ROYAL_BLUE: Self = Self::new(0.25490198, 0.4117647, 0.88235295, 1.0);
+    pub const SADDLE_BROWN: Self = Self::new(0.54509807, 0.27058825, 0.07450981, 1.0);
+    pub const SALMON: Self = Self::new(0.98039216, 0.5019608, 0.44705883, 1.0);
+    pub const SANDY_BROWN: Self = Self::new(0.95686275, 0.6431373, 0.3764706, 1.0);
+    pub const SEA_GREEN: Self = Self::new(0.18039216, 0.54509807, 0.34117648, 1.0);
+    pub const SEASHELL: Self = Self::new(1.0, 0.9607843, 0.93333334, 1.0);
+    pub const SIENNA: Self = Self::new(0.627451, 0.32156864, 0.1764706, 1.0);
+    pub const SILVER: Self = Self::new(0.7529412, 0.7529412, 0.7529412, 1.0);
+    pub const SKY_BLUE: Self = Self::new(0.5294118, 0.80784315, 0.92156863, 1.0);
+    pub const SLATE_BLUE: Self = Self::new(0.41568628, 0.3529412, 0.8039216, 1.0);
+    pub const SLATE_GRAY: Self = Self::new(0.4392157, 0.5019608, 0.5647059, 1.0);
+    pub const SNOW: Self = Self::new(1.0, 0.98039216, 0.98039216, 1.0);
+    pub const SPRING_GREEN: Self = Self::new(0.0, 1.0, 0.49803922, 1.0);
+    pub const STEEL_BLUE: Self = Self::new(0.27450982, 0.50980395, 0.7058824, 1.0);
+    pub const TAN: Self = Self::new(0.8235294, 0.7058824, 0.54901963, 1.0);
+    pub const TEAL: Self = Self::new(0.0, 0.5019608, 0.5019608, 1.0);
+    pub const THISTLE: Self = Self::new(0.84705883, 0.7490196, 0.84705883, 1.0);
+    pub const TOMATO: Self = Self::new(1.0, 0.3882353, 0.2784314, 1.0);
+    pub const TRANSPARENT: Self = Self::new(1.0, 1.0, 1.0, 0.0);
+    pub const TURQUOISE: Self = Self::new(0.2509804, 0.8784314, 0.8156863, 1.0);
+    pub const VIOLET: Self = Self::new(0.93333334, 0.50980395, 0.93333334, 1.0);
+    pub const WEB_GRAY: Self = Self::new(0.5019608, 0.5019608, 0.5019608, 1.0);
+    pub const WEB_GREEN: Self = Self::new(0.0, 0.5019608, 0.0, 1.0);
+    pub const WEB_MAROON: Self = Self::new(0.5019608, 0.0, 0.0, 1.0);
+    pub const WEB_PURPLE: Self = Self::new(0.5019608, 0.0, 0.5019608, 1.0);
+    pub const WHEAT: Self = Self::new(0.9607843, 0.87058824, 0.7019608, 1.0);
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+    pub const WHITE_SMOKE: Self = Self::new(0.9607843, 0.9607843, 0.9607843, 1.0);
+    pub const YELLOW: Self = Self::new(1.0, 1.0, 0.0, 1.0);
+    pub const YELLOW_GREEN: Self = Self::new(0.6039216, 0.8039216, 0.19607843, 1.0);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// OKHSL
+
+/// Conversions between sRGB and OKHSL, after Björn Ottosson's reference implementation (also used by Godot).
+///
+/// See <https://bottosson.github.io/posts/colorpicker/>.
+mod ok_hsl {
+    // Constants are kept verbatim from the reference implementation
+    #![allow(clippy::excessive_precision)]
+
+    use super::Color;
+    use std::f32::consts::PI;
+
+    /// Lightness and chroma.
+    type Lc = (f32, f32);
+
+    /// Converts OKHSL to (gamma-encoded) sRGB, without clamping.
+    pub fn to_srgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+        if l >= 1.0 {
+            return (1.0, 1.0, 1.0);
+        } else if l <= 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let a_ = (2.0 * PI * h).cos();
+        let b_ = (2.0 * PI * h).sin();
+        let lightness = toe_inv(l);
+        let (c_0, c_mid, c_max) = get_cs(lightness, a_, b_);
+
+        let chroma = if s < MID {
+            let t = MID_INV * s;
+            let k_1 = MID * c_0;
+            let k_2 = 1.0 - k_1 / c_mid;
+
+            t * k_1 / (1.0 - k_2 * t)
+        } else {
+            let t = (s - MID) / (1.0 - MID);
+            let k_0 = c_mid;
+            let k_1 = (1.0 - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+            let k_2 = 1.0 - k_1 / (c_max - c_mid);
+
+            k_0 + t * k_1 / (1.0 - k_2 * t)
+        };
+
+        let (r, g, b) = oklab_to_linear_srgb(lightness, chroma * a_, chroma * b_);
+        let srgb = Color::new(r, g, b, 1.0).linear_to_srgb();
+        (srgb.r, srgb.g, srgb.b)
+    }
+
+    /// Converts (gamma-encoded) sRGB to OKHSL. Hue and saturation are NaN for grays.
+    pub fn from_srgb(color: Color) -> (f32, f32, f32) {
+        let linear = color.srgb_to_linear();
+        let (lightness, a, b) = linear_srgb_to_oklab(linear.r, linear.g, linear.b);
+
+        let chroma = (a * a + b * b).sqrt();
+        let a_ = a / chroma;
+        let b_ = b / chroma;
+        let h = 0.5 + 0.5 * (-b).atan2(-a) / PI;
+
+        let (c_0, c_mid, c_max) = get_cs(lightness, a_, b_);
+        let s = if chroma < c_mid {
+            let k_1 = MID * c_0;
+            let k_2 = 1.0 - k_1 / c_mid;
+
+            let t = chroma / (k_1 + k_2 * chroma);
+            t * MID
+        } else {
+            let k_0 = c_mid;
+            let k_1 = (1.0 - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+            let k_2 = 1.0 - k_1 / (c_max - c_mid);
+
+            let t = (chroma - k_0) / (k_1 + k_2 * (chroma - k_0));
+            MID + (1.0 - MID) * t
+        };
+
+        (h, s, toe(lightness))
+    }
+
+    /// Clamps a component to `0.0..=1.0`, mapping NaN (e.g. the hue of grays) to 0, like Godot.
+    pub fn component(value: f32) -> f32 {
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        }
+    }
+
+    // Saturation at which chroma reaches C_mid
+    const MID: f32 = 0.8;
+    const MID_INV: f32 = 1.25;
+
+    fn linear_srgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        (
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        )
+    }
+
+    fn oklab_to_linear_srgb(lightness: f32, a: f32, b: f32) -> (f32, f32, f32) {
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        (
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        )
+    }
+
+    /// Maximum saturation `C/L` in sRGB for the hue `(a, b)`, which must be normalized.
+    fn compute_max_saturation(a: f32, b: f32) -> f32 {
+        // Select coefficients depending on which of r, g or b goes below zero first
+        let (k0, k1, k2, k3, k4, wl, wm, ws) = if -1.88170328 * a - 0.80936493 * b > 1.0 {
+            (
+                1.19086277,
+                1.76576728,
+                0.59662641,
+                0.75515197,
+                0.56771245,
+                4.0767416621,
+                -3.3077115913,
+                0.2309699292,
+            )
+        } else if 1.81444104 * a - 1.19445276 * b > 1.0 {
+            (
+                0.73956515,
+                -0.45954404,
+                0.08285427,
+                0.12541070,
+                0.14503204,
+                -1.2684380046,
+                2.6097574011,
+                -0.3413193965,
+            )
+        } else {
+            (
+                1.35733652,
+                -0.00915799,
+                -1.15130210,
+                -0.50559606,
+                0.00692167,
+                -0.0041960863,
+                -0.7034186147,
+                1.7076147010,
+            )
+        };
+
+        // Polynomial approximation, refined by one step of Halley's method
+        let saturation = k0 + k1 * a + k2 * b + k3 * a * a + k4 * a * b;
+
+        let k_l = 0.3963377774 * a + 0.2158037573 * b;
+        let k_m = -0.1055613458 * a - 0.0638541728 * b;
+        let k_s = -0.0894841775 * a - 1.2914855480 * b;
+
+        let l_ = 1.0 + saturation * k_l;
+        let m_ = 1.0 + saturation * k_m;
+        let s_ = 1.0 + saturation * k_s;
+
+        let f = wl * l_.powi(3) + wm * m_.powi(3) + ws * s_.powi(3);
+        let f1 = wl * 3.0 * k_l * l_ * l_ + wm * 3.0 * k_m * m_ * m_ + ws * 3.0 * k_s * s_ * s_;
+        let f2 = wl * 6.0 * k_l * k_l * l_ + wm * 6.0 * k_m * k_m * m_ + ws * 6.0 * k_s * k_s * s_;
+
+        saturation - f * f1 / (f1 * f1 - 0.5 * f * f2)
+    }
+
+    /// Lightness and chroma of the most saturated color of the hue `(a, b)`, which must be normalized.
+    fn find_cusp(a: f32, b: f32) -> Lc {
+        let s_cusp = compute_max_saturation(a, b);
+
+        // Scale so that the largest linear sRGB component is 1
+        let (r, g, b) = oklab_to_linear_srgb(1.0, s_cusp * a, s_cusp * b);
+        let l_cusp = (1.0 / r.max(g).max(b)).cbrt();
+
+        (l_cusp, l_cusp * s_cusp)
+    }
+
+    /// Finds `t` where the line `L = L0 * (1 - t) + t * L1, C = t * C1` leaves the sRGB gamut.
+    fn find_gamut_intersection(a: f32, b: f32, l1: f32, c1: f32, l0: f32, cusp: Lc) -> f32 {
+        let (l_cusp, c_cusp) = cusp;
+
+        if (l1 - l0) * c_cusp - (l_cusp - l0) * c1 <= 0.0 {
+            // Lower half
+            return c_cusp * l0 / (c1 * l_cusp + c_cusp * (l0 - l1));
+        }
+
+        // Upper half: intersect with the triangle, then refine by one step of Halley's method
+        let t = c_cusp * (l0 - 1.0) / (c1 * (l_cusp - 1.0) + c_cusp * (l0 - l1));
+
+        let dl = l1 - l0;
+        let dc = c1;
+
+        let k_l = 0.3963377774 * a + 0.2158037573 * b;
+        let k_m = -0.1055613458 * a - 0.0638541728 * b;
+        let k_s = -0.0894841775 * a - 1.2914855480 * b;
+
+        let l_dt = dl + dc * k_l;
+        let m_dt = dl + dc * k_m;
+        let s_dt = dl + dc * k_s;
+
+        let lightness = l0 * (1.0 - t) + t * l1;
+        let chroma = t * c1;
+
+        let l_ = lightness + chroma * k_l;
+        let m_ = lightness + chroma * k_m;
+        let s_ = lightness + chroma * k_s;
+
+        let lms = [l_.powi(3), m_.powi(3), s_.powi(3)];
+        let lms_dt = [
+            3.0 * l_dt * l_ * l_,
+            3.0 * m_dt * m_ * m_,
+            3.0 * s_dt * s_ * s_,
+        ];
+        let lms_dt2 = [
+            6.0 * l_dt * l_dt * l_,
+            6.0 * m_dt * m_dt * m_,
+            6.0 * s_dt * s_dt * s_,
+        ];
+
+        // Step for each of r, g and b towards 1
+        let step = |[wl, wm, ws]: [f32; 3]| {
+            let dot = |v: [f32; 3]| wl * v[0] + wm * v[1] + ws * v[2];
+            let f = dot(lms) - 1.0;
+            let f1 = dot(lms_dt);
+            let f2 = dot(lms_dt2);
+
+            let u = f1 / (f1 * f1 - 0.5 * f * f2);
+            if u >= 0.0 {
+                -f * u
+            } else {
+                f32::MAX
+            }
+        };
+
+        let t_r = step([4.0767416621, -3.3077115913, 0.2309699292]);
+        let t_g = step([-1.2684380046, 2.6097574011, -0.3413193965]);
+        let t_b = step([-0.0041960863, -0.7034186147, 1.7076147010]);
+
+        t + t_r.min(t_g).min(t_b)
+    }
+
+    fn toe(x: f32) -> f32 {
+        let (k_1, k_2, k_3) = toe_constants();
+        0.5 * (k_3 * x - k_1 + ((k_3 * x - k_1) * (k_3 * x - k_1) + 4.0 * k_2 * k_3 * x).sqrt())
+    }
+
+    fn toe_inv(x: f32) -> f32 {
+        let (k_1, k_2, k_3) = toe_constants();
+        (x * x + k_1 * x) / (k_3 * (x + k_2))
+    }
+
+    fn toe_constants() -> (f32, f32, f32) {
+        let k_1 = 0.206;
+        let k_2 = 0.03;
+        (k_1, k_2, (1.0 + k_1) / (1.0 + k_2))
+    }
+
+    /// Smooth approximation of the cusp's `(C/L, C/(1-L))`, designed to lie within the gamut.
+    fn get_st_mid(a_: f32, b_: f32) -> (f32, f32) {
+        let s = 0.11516993
+            + 1.0
+                / (7.44778970
+                    + 4.15901240 * b_
+                    + a_ * (-2.19557347
+                        + 1.75198401 * b_
+                        + a_ * (-2.13704948 - 10.02301043 * b_
+                            + a_ * (-4.24894561 + 5.38770819 * b_ + 4.69891013 * a_))));
+
+        let t = 0.11239642
+            + 1.0
+                / (1.61320320 - 0.68124379 * b_
+                    + a_ * (0.40370612
+                        + 0.90148123 * b_
+                        + a_ * (-0.27087943
+                            + 0.61223990 * b_
+                            + a_ * (0.00299215 - 0.45399568 * b_ - 0.14661872 * a_))));
+
+        (s, t)
+    }
+
+    /// Chroma at zero, mid and maximal saturation, for the lightness `l` and hue `(a_, b_)`.
+    fn get_cs(l: f32, a_: f32, b_: f32) -> (f32, f32, f32) {
+        let cusp = find_cusp(a_, b_);
+        let c_max = find_gamut_intersection(a_, b_, l, 1.0, l, cusp);
+
+        // Scale factor to compensate for the curved part of the gamut shape
+        let (l_cusp, c_cusp) = cusp;
+        let (s_max, t_max) = (c_cusp / l_cusp, c_cusp / (1.0 - l_cusp));
+        let k = c_max / (l * s_max).min((1.0 - l) * t_max);
+
+        // Soft minimum instead of a sharp triangle, for a smooth chroma
+        let (s_mid, t_mid) = get_st_mid(a_, b_);
+        let c_a = l * s_mid;
+        let c_b = (1.0 - l) * t_mid;
+        let c_mid = 0.9
+            * k
+            * (1.0 / (1.0 / c_a.powi(4) + 1.0 / c_b.powi(4)))
+                .sqrt()
+                .sqrt();
+
+        // The shape at zero saturation is independent of the hue
+        let c_a = l * 0.4;
+        let c_b = (1.0 - l) * 0.8;
+        let c_0 = (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt();
+
+        (c_0, c_mid, c_max)
     }
 }
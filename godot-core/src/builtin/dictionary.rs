@@ -0,0 +1,341 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+
+use godot_ffi as sys;
+use sys::types::OpaqueDictionary;
+use sys::{ffi_methods, interface_fn, GodotFfi};
+
+use crate::builtin::{Array, FromVariant, ToVariant, Variant, VariantConversionError, VariantIter};
+
+/// Godot's `Dictionary` type, mapping [`Variant`] keys to `Variant` values. Preserves insertion order.
+///
+/// Dictionaries are reference-counted: [`Clone`] creates a new reference to the _same_ dictionary.
+/// Use [`Dictionary::duplicate_shallow()`] or [`Dictionary::duplicate_deep()`] for an independent copy.
+#[repr(C)]
+pub struct Dictionary {
+    opaque: OpaqueDictionary,
+}
+
+impl Dictionary {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        let len = unsafe { builtin_call!(dictionary_size(self.sys()) -> i64) };
+        len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe { builtin_call!(dictionary_is_empty(self.sys()) -> bool) }
+    }
+
+    /// Returns the value for `key`, or `None` if there is no such entry.
+    pub fn get<K: ToVariant>(&self, key: K) -> Option<Variant> {
+        self.try_get_variant(&key.to_variant())
+    }
+
+    /// Returns the value for `key`, or a nil variant if there is no such entry.
+    ///
+    /// Note that this cannot distinguish between absent keys and keys mapped to nil; use [`Dictionary::get()`] for that.
+    pub fn get_or_nil<K: ToVariant>(&self, key: K) -> Variant {
+        self.get_variant(&key.to_variant())
+    }
+
+    pub fn contains_key<K: ToVariant>(&self, key: K) -> bool {
+        self.contains_variant(&key.to_variant())
+    }
+
+    /// Inserts `value` for `key`, returning the previous value if there was one.
+    pub fn insert<K: ToVariant, V: ToVariant>(&mut self, key: K, value: V) -> Option<Variant> {
+        let key = key.to_variant();
+        let old_value = self.try_get_variant(&key);
+
+        unsafe {
+            // Creates the entry if absent; the assignment drops the previous value in place
+            let slot = interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys());
+            *(slot as *mut Variant) = value.to_variant();
+        }
+
+        old_value
+    }
+
+    /// Removes the entry for `key`, returning its value if there was one.
+    pub fn remove<K: ToVariant>(&mut self, key: K) -> Option<Variant> {
+        let key = key.to_variant();
+        let old_value = self.try_get_variant(&key);
+
+        if old_value.is_some() {
+            unsafe {
+                builtin_call!(dictionary_erase(self.sys_mut(), key.sys()) -> bool);
+            }
+        }
+
+        old_value
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        unsafe { builtin_call!(dictionary_clear(self.sys_mut())) }
+    }
+
+    /// Returns a new array with all keys, in insertion order.
+    pub fn keys(&self) -> Array {
+        unsafe { builtin_call!(dictionary_keys(self.sys()) -> Array) }
+    }
+
+    /// Returns a new array with all values, in insertion order.
+    pub fn values(&self) -> Array {
+        unsafe { builtin_call!(dictionary_values(self.sys()) -> Array) }
+    }
+
+    /// Copies all entries of `other` into this dictionary.
+    ///
+    /// Entries for keys present in both dictionaries are only replaced if `overwrite` is `true`.
+    pub fn merge(&mut self, other: &Dictionary, overwrite: bool) {
+        unsafe {
+            builtin_call!(dictionary_merge(
+                self.sys_mut(),
+                other.sys(),
+                overwrite.sys()
+            ))
+        }
+    }
+
+    /// Returns a copy of this dictionary, sharing nested arrays and dictionaries with the original.
+    pub fn duplicate_shallow(&self) -> Self {
+        self.duplicate(false)
+    }
+
+    /// Returns a copy of this dictionary, recursively copying nested arrays and dictionaries.
+    pub fn duplicate_deep(&self) -> Self {
+        self.duplicate(true)
+    }
+
+    /// Iterates over `(key, value)` pairs, in insertion order.
+    pub fn iter(&self) -> DictionaryIter<'_> {
+        DictionaryIter::new(self)
+    }
+
+    /// Iterates over `(key, value)` pairs converted to `K` and `V`, in insertion order.
+    ///
+    /// # Panics
+    /// During iteration, if a key or value cannot be converted to its target type.
+    pub fn iter_typed<K: FromVariant, V: FromVariant>(&self) -> TypedDictionaryIter<'_, K, V> {
+        TypedDictionaryIter {
+            inner: self.iter(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn duplicate(&self, deep: bool) -> Self {
+        unsafe { builtin_call!(dictionary_duplicate(self.sys(), deep.sys()) -> Self) }
+    }
+
+    fn contains_variant(&self, key: &Variant) -> bool {
+        unsafe { builtin_call!(dictionary_has(self.sys(), key.sys()) -> bool) }
+    }
+
+    fn try_get_variant(&self, key: &Variant) -> Option<Variant> {
+        self.contains_variant(key).then(|| self.get_variant(key))
+    }
+
+    fn get_variant(&self, key: &Variant) -> Variant {
+        let default = Variant::nil();
+        unsafe {
+            builtin_call!(dictionary_get(
+                self.sys(),
+                key.sys(),
+                default.sys(),
+            ) -> Variant)
+        }
+    }
+}
+
+impl GodotFfi for Dictionary {
+    ffi_methods! {
+        type sys::GDNativeTypePtr = *mut Opaque;
+        fn sys;
+        fn write_sys;
+    }
+
+    impl_from_sys_as_copy!(from_sys => dictionary_construct_copy);
+
+    unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+        // Can't use uninitialized pointer -- Dictionary implementation in C++ expects that on assignment,
+        // the target type is a valid dictionary (possibly empty)
+
+        let mut result = Self::default();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_traits_as_sys! {
+    for Dictionary {
+        Default => dictionary_construct_default;
+        Clone => dictionary_construct_copy;
+        Drop => dictionary_destroy;
+        PartialEq => dictionary_operator_equal;
+    }
+}
+
+impl fmt::Display for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
+
+impl fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dictionary({})", self.to_variant())
+    }
+}
+
+impl<'a> IntoIterator for &'a Dictionary {
+    type Item = (Variant, Variant);
+    type IntoIter = DictionaryIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> FromIterator<(K, V)> for Dictionary {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dictionary = Self::new();
+        dictionary.extend(iter);
+        dictionary
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> Extend<(K, V)> for Dictionary {
+    /// Inserts all pairs, replacing existing entries with the same key.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, S> From<&HashMap<K, V, S>> for Dictionary
+where
+    K: ToVariant,
+    V: ToVariant,
+{
+    fn from(map: &HashMap<K, V, S>) -> Self {
+        map.iter()
+            .map(|(key, value)| (key.to_variant(), value.to_variant()))
+            .collect()
+    }
+}
+
+/// Fails if any key or value cannot be converted.
+impl<K, V, S> TryFrom<&Dictionary> for HashMap<K, V, S>
+where
+    K: FromVariant + Eq + Hash,
+    V: FromVariant,
+    S: BuildHasher + Default,
+{
+    type Error = VariantConversionError;
+
+    fn try_from(dictionary: &Dictionary) -> Result<Self, Self::Error> {
+        dictionary
+            .iter()
+            .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+            .collect()
+    }
+}
+
+impl<K, V> From<&BTreeMap<K, V>> for Dictionary
+where
+    K: ToVariant,
+    V: ToVariant,
+{
+    fn from(map: &BTreeMap<K, V>) -> Self {
+        map.iter()
+            .map(|(key, value)| (key.to_variant(), value.to_variant()))
+            .collect()
+    }
+}
+
+/// Fails if any key or value cannot be converted.
+impl<K, V> TryFrom<&Dictionary> for BTreeMap<K, V>
+where
+    K: FromVariant + Ord,
+    V: FromVariant,
+{
+    type Error = VariantConversionError;
+
+    fn try_from(dictionary: &Dictionary) -> Result<Self, Self::Error> {
+        dictionary
+            .iter()
+            .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Iterators
+
+/// Iterator over a dictionary's `(key, value)` pairs, see [`Dictionary::iter()`].
+pub struct DictionaryIter<'a> {
+    dictionary: &'a Dictionary,
+
+    /// Iterates over the keys, through Godot's iteration API for the dictionary as a variant.
+    keys: VariantIter,
+}
+
+impl<'a> DictionaryIter<'a> {
+    fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            dictionary,
+            keys: VariantIter::new(dictionary.to_variant()),
+        }
+    }
+}
+
+impl<'a> Iterator for DictionaryIter<'a> {
+    type Item = (Variant, Variant);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let value = self.dictionary.get_variant(&key);
+        Some((key, value))
+    }
+}
+
+/// Iterator over a dictionary's `(key, value)` pairs as concrete types, see [`Dictionary::iter_typed()`].
+pub struct TypedDictionaryIter<'a, K, V> {
+    inner: DictionaryIter<'a>,
+    _phantom: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'a, K: FromVariant, V: FromVariant> Iterator for TypedDictionaryIter<'a, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.inner.next()?;
+
+        Some((convert_entry(&key, "key"), convert_entry(&value, "value")))
+    }
+}
+
+fn convert_entry<T: FromVariant>(variant: &Variant, what: &str) -> T {
+    T::try_from_variant(variant).unwrap_or_else(|e| {
+        panic!(
+            "dictionary {what} {variant:?} cannot be converted to {}: {e:?}",
+            std::any::type_name::<T>()
+        )
+    })
+}
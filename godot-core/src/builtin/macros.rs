@@ -11,7 +11,10 @@ macro_rules! impl_basic_trait_as_sys {
         impl Drop for $Type {
             #[inline]
             fn drop(&mut self) {
-                unsafe { (get_api().$gd_method)(self.sys_mut()) }
+                unsafe {
+                    let destructor = godot_ffi::method_table().$gd_method;
+                    destructor(self.sys_mut());
+                }
             }
         }
     };
@@ -20,10 +23,16 @@ macro_rules! impl_basic_trait_as_sys {
         impl Clone for $Type {
             #[inline]
             fn clone(&self) -> Self {
+                // Note: can't use from_sys_init(), as that may pre-initialize the target (constructors expect raw memory)
+                let mut uninit = std::mem::MaybeUninit::<$Type>::uninit();
+
                 unsafe {
-                    let mut result = sys::$GdType::default();
-                    (get_api().$gd_method)(&mut result, self.sys());
-                    <$Type>::from_sys(result)
+                    let self_ptr = (*uninit.as_mut_ptr()).sys_mut();
+                    let ctor = godot_ffi::method_table().$gd_method;
+                    let args = [self.sys()];
+                    ctor(self_ptr, args.as_ptr());
+
+                    uninit.assume_init()
                 }
             }
         }
@@ -33,10 +42,15 @@ macro_rules! impl_basic_trait_as_sys {
         impl Default for $Type {
             #[inline]
             fn default() -> Self {
+                // Note: can't use from_sys_init(), as that may call the default constructor
+                let mut uninit = std::mem::MaybeUninit::<$Type>::uninit();
+
                 unsafe {
-                    let mut gd_val = sys::$GdType::default();
-                    (get_api().$gd_method)(&mut gd_val);
-                    <$Type>::from_sys(gd_val)
+                    let self_ptr = (*uninit.as_mut_ptr()).sys_mut();
+                    let ctor = godot_ffi::method_table().$gd_method;
+                    ctor(self_ptr, std::ptr::null_mut());
+
+                    uninit.assume_init()
                 }
             }
         }
@@ -110,22 +124,19 @@ macro_rules! impl_traits_as_sys {
     )
 }
 
-macro_rules! impl_builtin_stub {
-    ($Class:ident, $OpaqueTy:ident) => {
-        #[repr(C)]
-        #[derive(Copy, Clone)]
-        pub struct $Class {
-            opaque: sys::types::$OpaqueTy,
-        }
+/// Implements `GodotFfi::from_sys()` through the copy constructor, for types which own a reference to Godot data.
+///
+/// The pointee is owned by Godot (e.g. a ptrcall argument). A bitwise copy would release Godot's reference on drop,
+/// so a new reference is taken instead. Must be invoked inside an `impl GodotFfi` block.
+macro_rules! impl_from_sys_as_copy {
+    ( from_sys => $gd_method:ident ) => {
+        unsafe fn from_sys(ptr: godot_ffi::GDNativeTypePtr) -> Self {
+            let mut uninit = std::mem::MaybeUninit::<Self>::uninit();
+            let self_ptr = (*uninit.as_mut_ptr()).sys_mut();
+            let ctor = godot_ffi::method_table().$gd_method;
+            ctor(self_ptr, [ptr].as_ptr());
 
-        impl $Class {
-            fn from_opaque(opaque: sys::types::$OpaqueTy) -> Self {
-                Self { opaque }
-            }
-        }
-
-        impl GodotFfi for $Class {
-            ffi_methods! { type sys::GDNativeTypePtr = *mut Opaque; .. }
+            uninit.assume_init()
         }
     };
 }
@@ -134,13 +145,183 @@ macro_rules! impl_builtin_froms {
     ($To:ty; $($From:ty => $from_fn:ident),* $(,)?) => {
         $(impl From<&$From> for $To {
             fn from(other: &$From) -> Self {
+                // Constructors expect raw memory, see Clone impl in impl_basic_trait_as_sys!
+                let mut uninit = std::mem::MaybeUninit::<Self>::uninit();
+
                 unsafe {
-                    Self::from_sys_init(|ptr| {
-                        let converter = sys::method_table().$from_fn;
-                        converter(ptr, [other.sys()].as_ptr());
-                    })
+                    let self_ptr = (*uninit.as_mut_ptr()).sys_mut();
+                    let converter = sys::method_table().$from_fn;
+                    converter(self_ptr, [other.sys()].as_ptr());
+
+                    uninit.assume_init()
                 }
             }
         })*
     };
 }
+
+/// Calls a builtin method through its ptrcall function in the global method table.
+///
+/// The receiver and all arguments are `sys::GDNativeTypePtr`s. Must be invoked in an `unsafe` context.
+/// ```ignore
+/// let len = builtin_call!(dictionary_size(self.sys()) -> i64);
+/// builtin_call!(dictionary_clear(self.sys_mut()));
+/// ```
+macro_rules! builtin_call {
+    ($method:ident( $self_ptr:expr $(, $arg:expr)* $(,)? ) -> $Ret:ty) => {{
+        let method = sys::method_table().$method;
+        let args: &[sys::GDNativeTypePtr] = &[$( $arg ),*];
+
+        <$Ret as sys::GodotFfi>::from_sys_init(|return_ptr| {
+            method($self_ptr, args.as_ptr(), return_ptr, args.len() as i32);
+        })
+    }};
+
+    ($method:ident( $self_ptr:expr $(, $arg:expr)* $(,)? )) => {{
+        let method = sys::method_table().$method;
+        let args: &[sys::GDNativeTypePtr] = &[$( $arg ),*];
+
+        method($self_ptr, args.as_ptr(), std::ptr::null_mut(), args.len() as i32);
+    }};
+}
+
+/// Implements component-wise arithmetic operators (`+`, `-`, `*`, `/`, unary `-`) for a vector type
+/// wrapping a glam `inner` value, together with scalar multiplication/division and the `*Assign` variants.
+macro_rules! impl_vector_operators {
+    ($Vector:ty, $Scalar:ty) => {
+        impl_vector_operators!(@binary $Vector, $Scalar; Add, add, AddAssign, add_assign);
+        impl_vector_operators!(@binary $Vector, $Scalar; Sub, sub, SubAssign, sub_assign);
+        impl_vector_operators!(@binary $Vector, $Scalar; Mul, mul, MulAssign, mul_assign);
+        impl_vector_operators!(@binary $Vector, $Scalar; Div, div, DivAssign, div_assign);
+        impl_vector_operators!(@scalar $Vector, $Scalar; Mul, mul, MulAssign, mul_assign);
+        impl_vector_operators!(@scalar $Vector, $Scalar; Div, div, DivAssign, div_assign);
+
+        impl std::ops::Mul<$Vector> for $Scalar {
+            type Output = $Vector;
+
+            #[inline]
+            fn mul(self, rhs: $Vector) -> $Vector {
+                rhs * self
+            }
+        }
+
+        impl std::ops::Neg for $Vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self::from_inner(-self.inner)
+            }
+        }
+    };
+
+    (@binary $Vector:ty, $Scalar:ty; $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl std::ops::$Op for $Vector {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                Self::from_inner(std::ops::$Op::$op(self.inner, rhs.inner))
+            }
+        }
+
+        impl std::ops::$OpAssign for $Vector {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = std::ops::$Op::$op(*self, rhs);
+            }
+        }
+    };
+
+    (@scalar $Vector:ty, $Scalar:ty; $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl std::ops::$Op<$Scalar> for $Vector {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: $Scalar) -> Self {
+                Self::from_inner(std::ops::$Op::$op(self.inner, rhs))
+            }
+        }
+
+        impl std::ops::$OpAssign<$Scalar> for $Vector {
+            #[inline]
+            fn $op_assign(&mut self, rhs: $Scalar) {
+                *self = std::ops::$Op::$op(*self, rhs);
+            }
+        }
+    };
+}
+
+/// Like `impl_vector_operators!`, additionally implementing `%` (`Rem`, `RemAssign`) for integer vectors.
+///
+/// Like in Godot, the operations wrap around on overflow, in all build profiles. Division or remainder by zero panics.
+macro_rules! impl_integer_vector_operators {
+    ($Vector:ty, $Scalar:ty) => {
+        impl_integer_vector_operators!(@binary $Vector, $Scalar; Add, add, AddAssign, add_assign, wrapping_add);
+        impl_integer_vector_operators!(@binary $Vector, $Scalar; Sub, sub, SubAssign, sub_assign, wrapping_sub);
+        impl_integer_vector_operators!(@binary $Vector, $Scalar; Mul, mul, MulAssign, mul_assign, wrapping_mul);
+        impl_integer_vector_operators!(@binary $Vector, $Scalar; Div, div, DivAssign, div_assign, wrapping_div);
+        impl_integer_vector_operators!(@binary $Vector, $Scalar; Rem, rem, RemAssign, rem_assign, wrapping_rem);
+        impl_integer_vector_operators!(@scalar $Vector, $Scalar; Mul, mul, MulAssign, mul_assign, wrapping_mul);
+        impl_integer_vector_operators!(@scalar $Vector, $Scalar; Div, div, DivAssign, div_assign, wrapping_div);
+        impl_integer_vector_operators!(@scalar $Vector, $Scalar; Rem, rem, RemAssign, rem_assign, wrapping_rem);
+
+        impl std::ops::Mul<$Vector> for $Scalar {
+            type Output = $Vector;
+
+            #[inline]
+            fn mul(self, rhs: $Vector) -> $Vector {
+                rhs * self
+            }
+        }
+
+        impl std::ops::Neg for $Vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self::from_inner(self.inner.to_array().map(<$Scalar>::wrapping_neg).into())
+            }
+        }
+    };
+
+    (@binary $Vector:ty, $Scalar:ty; $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $wrapping_op:ident) => {
+        impl std::ops::$Op for $Vector {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                let mut components = self.inner.to_array();
+                for (lhs, rhs) in components.iter_mut().zip(rhs.inner.to_array()) {
+                    *lhs = lhs.$wrapping_op(rhs);
+                }
+                Self::from_inner(components.into())
+            }
+        }
+
+        impl std::ops::$OpAssign for $Vector {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = std::ops::$Op::$op(*self, rhs);
+            }
+        }
+    };
+
+    (@scalar $Vector:ty, $Scalar:ty; $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $wrapping_op:ident) => {
+        impl std::ops::$Op<$Scalar> for $Vector {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, rhs: $Scalar) -> Self {
+                Self::from_inner(self.inner.to_array().map(|c| c.$wrapping_op(rhs)).into())
+            }
+        }
+
+        impl std::ops::$OpAssign<$Scalar> for $Vector {
+            #[inline]
+            fn $op_assign(&mut self, rhs: $Scalar) {
+                *self = std::ops::$Op::$op(*self, rhs);
+            }
+        }
+    };
+}
@@ -0,0 +1,74 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Scalar math functions, mirroring Godot's `Math` namespace (`core/math/math_funcs.h`).
+
+use std::f32::consts::TAU;
+
+/// Tolerance used by Godot for approximate float comparisons.
+pub const CMP_EPSILON: f32 = 0.00001;
+
+/// Returns `true` if `a` and `b` are approximately equal.
+///
+/// The tolerance scales with the magnitude of `a`, but is never smaller than [`CMP_EPSILON`].
+pub fn is_equal_approx(a: f32, b: f32) -> bool {
+    if a == b {
+        // Shortcut, also handles infinities
+        return true;
+    }
+
+    let tolerance = (CMP_EPSILON * a.abs()).max(CMP_EPSILON);
+    (a - b).abs() < tolerance
+}
+
+/// Returns `true` if `value` is approximately zero.
+pub fn is_zero_approx(value: f32) -> bool {
+    value.abs() < CMP_EPSILON
+}
+
+/// Linearly interpolates between `from` and `to` by `weight`.
+pub fn lerp(from: f32, to: f32, weight: f32) -> f32 {
+    from + (to - from) * weight
+}
+
+/// Linearly interpolates between the angles `from` and `to` (in radians), along the shortest path.
+pub fn lerp_angle(from: f32, to: f32, weight: f32) -> f32 {
+    let difference = (to - from) % TAU;
+    let distance = (2.0 * difference) % TAU - difference;
+    from + distance * weight
+}
+
+/// Snaps `value` to the nearest multiple of `step`. A `step` of zero leaves the value unchanged.
+pub fn snapped(value: f32, step: f32) -> f32 {
+    if step != 0.0 {
+        (value / step + 0.5).floor() * step
+    } else {
+        value
+    }
+}
+
+/// Returns `-1.0`, `0.0` or `1.0` depending on the sign of `value`.
+///
+/// Unlike [`f32::signum()`], zero maps to zero.
+pub fn sign(value: f32) -> f32 {
+    if value > 0.0 {
+        1.0
+    } else if value < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+/// Wraps `value` into the range `[min, max)`.
+pub fn wrapf(value: f32, min: f32, max: f32) -> f32 {
+    let range = max - min;
+    if is_zero_approx(range) {
+        min
+    } else {
+        value - range * ((value - min) / range).floor()
+    }
+}
@@ -8,21 +8,49 @@
 
 mod macros;
 
+mod aabb;
 mod arrays;
+mod basis;
+mod callable;
 mod color;
-mod others;
+mod dictionary;
+mod node_path;
+mod packed_arrays;
+mod plane;
+mod projection;
+mod quaternion;
+mod rect2;
+mod rid;
+mod signal;
 mod string;
 mod string_name;
+mod transform2d;
+mod transform3d;
 mod variant;
 mod vector2;
 mod vector3;
 mod vector4;
 
+pub mod math;
+
+pub use aabb::*;
 pub use arrays::*;
+pub use basis::*;
+pub use callable::*;
 pub use color::*;
-pub use others::*;
+pub use dictionary::*;
+pub use node_path::*;
+pub use packed_arrays::*;
+pub use plane::*;
+pub use projection::*;
+pub use quaternion::*;
+pub use rect2::*;
+pub use rid::*;
+pub use signal::*;
 pub use string::*;
 pub use string_name::*;
+pub use transform2d::*;
+pub use transform3d::*;
 pub use variant::*;
 pub use vector2::*;
 pub use vector3::*;
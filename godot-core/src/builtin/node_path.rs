@@ -0,0 +1,231 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use godot_ffi as sys;
+use sys::types::OpaqueNodePath;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{GodotString, StringName};
+
+/// A pre-parsed path to a node or property, such as `"../Player"` or `"Sprite:modulate:a"`.
+///
+/// A path consists of _names_ (the node hierarchy, separated by `/`) followed by optional _subnames_
+/// (nested properties or resources, each introduced by `:`). Absolute paths start with `/`.
+#[repr(C)]
+pub struct NodePath {
+    opaque: OpaqueNodePath,
+}
+
+impl NodePath {
+    /// Creates an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the path starts at the scene tree root, i.e. begins with `/`.
+    pub fn is_absolute(&self) -> bool {
+        unsafe { builtin_call!(node_path_is_absolute(self.sys()) -> bool) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe { builtin_call!(node_path_is_empty(self.sys()) -> bool) }
+    }
+
+    /// Number of node names, e.g. 2 for `"Path2D/Sprite:modulate:a"`.
+    pub fn get_name_count(&self) -> usize {
+        let count = unsafe { builtin_call!(node_path_get_name_count(self.sys()) -> i64) };
+        count as usize
+    }
+
+    /// Returns the node name at `index`, or `None` if out of bounds.
+    pub fn get_name(&self, index: usize) -> Option<StringName> {
+        if index >= self.get_name_count() {
+            return None;
+        }
+
+        let index = index as i64;
+        let name =
+            unsafe { builtin_call!(node_path_get_name(self.sys(), index.sys()) -> StringName) };
+        Some(name)
+    }
+
+    /// Number of subnames, e.g. 2 for `"Path2D/Sprite:modulate:a"`.
+    pub fn get_subname_count(&self) -> usize {
+        let count = unsafe { builtin_call!(node_path_get_subname_count(self.sys()) -> i64) };
+        count as usize
+    }
+
+    /// Returns the subname at `index`, or `None` if out of bounds.
+    pub fn get_subname(&self, index: usize) -> Option<StringName> {
+        if index >= self.get_subname_count() {
+            return None;
+        }
+
+        let index = index as i64;
+        let subname =
+            unsafe { builtin_call!(node_path_get_subname(self.sys(), index.sys()) -> StringName) };
+        Some(subname)
+    }
+
+    /// All node names joined by `/`, without leading `/` or subnames. `"/root/Level:visible"` yields `"root/Level"`.
+    pub fn get_concatenated_names(&self) -> StringName {
+        unsafe { builtin_call!(node_path_get_concatenated_names(self.sys()) -> StringName) }
+    }
+
+    /// All subnames joined by `:`. `"Sprite:modulate:a"` yields `"modulate:a"`.
+    pub fn get_concatenated_subnames(&self) -> StringName {
+        unsafe { builtin_call!(node_path_get_concatenated_subnames(self.sys()) -> StringName) }
+    }
+
+    /// Returns the path as a property path relative to the current node, e.g. `"Sprite:modulate"` becomes `":Sprite:modulate"`.
+    pub fn as_property_path(&self) -> NodePath {
+        unsafe { builtin_call!(node_path_get_as_property_path(self.sys()) -> NodePath) }
+    }
+
+    /// Appends the names and subnames of `other` to the names of `self`.
+    ///
+    /// Like [`std::path::Path::join()`], an absolute `other` replaces `self` entirely. Subnames of `self` are dropped,
+    /// since they can only occur at the end of a path.
+    pub fn join(&self, other: &NodePath) -> NodePath {
+        if other.is_absolute() {
+            return other.clone();
+        }
+
+        let names = self.names().chain(other.names()).collect::<Vec<_>>();
+        let subnames = other.subnames().collect::<Vec<_>>();
+
+        Self::from_parts(self.is_absolute(), &names, &subnames)
+    }
+
+    /// Returns a copy of this path with one more node name appended. Existing subnames are dropped.
+    pub fn with_name(&self, name: impl Into<StringName>) -> NodePath {
+        let mut names = self.names().collect::<Vec<_>>();
+        names.push(name.into());
+
+        Self::from_parts(self.is_absolute(), &names, &[])
+    }
+
+    /// Returns a copy of this path with one more subname appended.
+    pub fn with_subname(&self, subname: impl Into<StringName>) -> NodePath {
+        let names = self.names().collect::<Vec<_>>();
+        let mut subnames = self.subnames().collect::<Vec<_>>();
+        subnames.push(subname.into());
+
+        Self::from_parts(self.is_absolute(), &names, &subnames)
+    }
+
+    fn names(&self) -> impl Iterator<Item = StringName> + '_ {
+        (0..self.get_name_count()).filter_map(|i| self.get_name(i))
+    }
+
+    fn subnames(&self) -> impl Iterator<Item = StringName> + '_ {
+        (0..self.get_subname_count()).filter_map(|i| self.get_subname(i))
+    }
+
+    fn from_parts(is_absolute: bool, names: &[StringName], subnames: &[StringName]) -> NodePath {
+        let mut path = String::new();
+        if is_absolute {
+            path.push('/');
+        }
+
+        let names = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+        path.push_str(&names.join("/"));
+
+        for subname in subnames {
+            path.push(':');
+            path.push_str(&subname.to_string());
+        }
+
+        NodePath::from(path.as_str())
+    }
+}
+
+impl GodotFfi for NodePath {
+    ffi_methods! {
+        type sys::GDNativeTypePtr = *mut Opaque;
+        fn sys;
+        fn write_sys;
+    }
+
+    impl_from_sys_as_copy!(from_sys => node_path_construct_copy);
+
+    unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+        // Can't use uninitialized pointer -- NodePath implementation in C++ expects that on assignment,
+        // the target type is a valid path (possibly empty)
+
+        let mut result = Self::default();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_traits_as_sys! {
+    for NodePath {
+        Default => node_path_construct_default;
+        Clone => node_path_construct_copy;
+        Drop => node_path_destroy;
+        Eq => node_path_operator_equal;
+    }
+}
+
+impl Hash for NodePath {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash = unsafe { builtin_call!(node_path_hash(self.sys()) -> i64) };
+        state.write_i64(hash)
+    }
+}
+
+impl From<&GodotString> for NodePath {
+    fn from(path: &GodotString) -> Self {
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::method_table().node_path_from_string;
+                let args = [path.sys()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+}
+
+impl From<&NodePath> for GodotString {
+    fn from(path: &NodePath) -> Self {
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::method_table().string_from_node_path;
+                let args = [path.sys()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+}
+
+impl From<&str> for NodePath {
+    fn from(path: &str) -> Self {
+        Self::from(&GodotString::from(path))
+    }
+}
+
+impl From<&NodePath> for NodePath {
+    fn from(path: &NodePath) -> Self {
+        path.clone()
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = GodotString::from(self);
+        <GodotString as fmt::Display>::fmt(&string, f)
+    }
+}
+
+impl fmt::Debug for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NodePath(\"{self}\")")
+    }
+}
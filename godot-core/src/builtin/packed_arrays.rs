@@ -0,0 +1,351 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::{Array, Color, GodotString, Vector2, Vector3};
+use std::fmt;
+use sys::{ffi_methods, interface_fn, types::*, GodotFfi};
+
+/// Implements a packed array type, i.e. a copy-on-write array storing its elements contiguously.
+macro_rules! impl_packed_array {
+    (
+        type_name: $PackedArray:ident,
+        element_type: $Element:ty,
+        opaque_type: $Opaque:ty,
+        inner_type: $Inner:literal,
+        construct_default: $construct_default:ident,
+        construct_copy: $construct_copy:ident,
+        destroy: $destroy:ident,
+        operator_equal: $operator_equal:ident,
+        operator_index: $operator_index:ident,
+        operator_index_const: $operator_index_const:ident,
+        size: $size:ident,
+        resize: $resize:ident,
+    ) => {
+        #[doc = concat!("Godot's `", $inner, "` type, a contiguous array of [`", stringify!($Element), "`] elements.")]
+        ///
+        /// Packed arrays are copy-on-write: [`Clone`] is cheap, and the elements are only copied once either of the
+        /// arrays is modified. The elements can be accessed without copies through [`Self::as_slice()`] and
+        /// [`Self::as_mut_slice()`].
+        #[repr(C)]
+        pub struct $PackedArray {
+            opaque: $Opaque,
+        }
+
+        impl $PackedArray {
+            /// Creates an empty array.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Number of elements.
+            pub fn len(&self) -> usize {
+                let len = unsafe { builtin_call!($size(self.sys()) -> i64) };
+                len as usize
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Borrows the elements, without copying them.
+            pub fn as_slice(&self) -> &[$Element] {
+                let len = self.len();
+                if len == 0 {
+                    return &[];
+                }
+
+                unsafe {
+                    let data = interface_fn!($operator_index_const)(self.sys(), 0);
+                    std::slice::from_raw_parts(data as *const $Element, len)
+                }
+            }
+
+            /// Mutably borrows the elements, without copying them.
+            ///
+            /// If the buffer is shared with other arrays, this array first receives its own copy (copy-on-write).
+            pub fn as_mut_slice(&mut self) -> &mut [$Element] {
+                let len = self.len();
+                if len == 0 {
+                    return &mut [];
+                }
+
+                unsafe {
+                    let data = interface_fn!($operator_index)(self.sys_mut(), 0);
+                    std::slice::from_raw_parts_mut(data as *mut $Element, len)
+                }
+            }
+
+            /// Returns a copy of the element at `index`, or `None` if `index` is out of bounds.
+            pub fn get(&self, index: usize) -> Option<$Element> {
+                self.as_slice().get(index).cloned()
+            }
+
+            /// Replaces the element at `index` with `value`.
+            ///
+            /// # Panics
+            /// If `index` is out of bounds.
+            pub fn set(&mut self, index: usize, value: $Element) {
+                self.as_mut_slice()[index] = value;
+            }
+
+            /// Appends `value` to the end of the array.
+            pub fn push(&mut self, value: $Element) {
+                let len = self.len();
+                self.resize(len + 1);
+                self.as_mut_slice()[len] = value;
+            }
+
+            /// Shrinks the array to `new_len` elements, or grows it by appending default values.
+            pub fn resize(&mut self, new_len: usize) {
+                let new_len = new_len as i64;
+                unsafe {
+                    builtin_call!($resize(self.sys_mut(), new_len.sys()) -> i64);
+                }
+            }
+
+            /// Appends copies of all elements in `values`.
+            pub fn extend_from_slice(&mut self, values: &[$Element]) {
+                let len = self.len();
+                self.resize(len + values.len());
+                self.as_mut_slice()[len..].clone_from_slice(values);
+            }
+
+            /// Iterates over the elements, in order.
+            pub fn iter(&self) -> std::slice::Iter<'_, $Element> {
+                self.as_slice().iter()
+            }
+        }
+
+        impl GodotFfi for $PackedArray {
+            ffi_methods! {
+                type sys::GDNativeTypePtr = *mut Opaque;
+                fn sys;
+                fn write_sys;
+            }
+
+            impl_from_sys_as_copy!(from_sys => $construct_copy);
+
+            unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+                // Can't use uninitialized pointer -- packed arrays are copy-on-write, and their implementation in C++
+                // expects that on assignment, the target type is a valid array (possibly empty)
+
+                let mut result = Self::default();
+                init_fn(result.sys_mut());
+                result
+            }
+        }
+
+        impl_traits_as_sys! {
+            for $PackedArray {
+                Default => $construct_default;
+                Clone => $construct_copy;
+                Drop => $destroy;
+                PartialEq => $operator_equal;
+            }
+        }
+
+        impl fmt::Debug for $PackedArray {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.as_slice()).finish()
+            }
+        }
+
+        impl From<&[$Element]> for $PackedArray {
+            fn from(values: &[$Element]) -> Self {
+                let mut array = Self::new();
+                array.extend_from_slice(values);
+                array
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $PackedArray {
+            type Item = &'a $Element;
+            type IntoIter = std::slice::Iter<'a, $Element>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl FromIterator<$Element> for $PackedArray {
+            fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
+                let mut array = Self::new();
+                array.extend(iter);
+                array
+            }
+        }
+
+        impl Extend<$Element> for $PackedArray {
+            fn extend<I: IntoIterator<Item = $Element>>(&mut self, iter: I) {
+                // Collect first, so the array is resized only once
+                let values: Vec<$Element> = iter.into_iter().collect();
+                self.extend_from_slice(&values);
+            }
+        }
+    };
+}
+
+impl_packed_array!(
+    type_name: ByteArray,
+    element_type: u8,
+    opaque_type: OpaquePackedByteArray,
+    inner_type: "PackedByteArray",
+    construct_default: packed_byte_array_construct_default,
+    construct_copy: packed_byte_array_construct_copy,
+    destroy: packed_byte_array_destroy,
+    operator_equal: packed_byte_array_operator_equal,
+    operator_index: packed_byte_array_operator_index,
+    operator_index_const: packed_byte_array_operator_index_const,
+    size: packed_byte_array_size,
+    resize: packed_byte_array_resize,
+);
+
+impl_packed_array!(
+    type_name: Int32Array,
+    element_type: i32,
+    opaque_type: OpaquePackedInt32Array,
+    inner_type: "PackedInt32Array",
+    construct_default: packed_int32_array_construct_default,
+    construct_copy: packed_int32_array_construct_copy,
+    destroy: packed_int32_array_destroy,
+    operator_equal: packed_int32_array_operator_equal,
+    operator_index: packed_int32_array_operator_index,
+    operator_index_const: packed_int32_array_operator_index_const,
+    size: packed_int32_array_size,
+    resize: packed_int32_array_resize,
+);
+
+impl_packed_array!(
+    type_name: Int64Array,
+    element_type: i64,
+    opaque_type: OpaquePackedInt64Array,
+    inner_type: "PackedInt64Array",
+    construct_default: packed_int64_array_construct_default,
+    construct_copy: packed_int64_array_construct_copy,
+    destroy: packed_int64_array_destroy,
+    operator_equal: packed_int64_array_operator_equal,
+    operator_index: packed_int64_array_operator_index,
+    operator_index_const: packed_int64_array_operator_index_const,
+    size: packed_int64_array_size,
+    resize: packed_int64_array_resize,
+);
+
+impl_packed_array!(
+    type_name: Float32Array,
+    element_type: f32,
+    opaque_type: OpaquePackedFloat32Array,
+    inner_type: "PackedFloat32Array",
+    construct_default: packed_float32_array_construct_default,
+    construct_copy: packed_float32_array_construct_copy,
+    destroy: packed_float32_array_destroy,
+    operator_equal: packed_float32_array_operator_equal,
+    operator_index: packed_float32_array_operator_index,
+    operator_index_const: packed_float32_array_operator_index_const,
+    size: packed_float32_array_size,
+    resize: packed_float32_array_resize,
+);
+
+impl_packed_array!(
+    type_name: Float64Array,
+    element_type: f64,
+    opaque_type: OpaquePackedFloat64Array,
+    inner_type: "PackedFloat64Array",
+    construct_default: packed_float64_array_construct_default,
+    construct_copy: packed_float64_array_construct_copy,
+    destroy: packed_float64_array_destroy,
+    operator_equal: packed_float64_array_operator_equal,
+    operator_index: packed_float64_array_operator_index,
+    operator_index_const: packed_float64_array_operator_index_const,
+    size: packed_float64_array_size,
+    resize: packed_float64_array_resize,
+);
+
+impl_packed_array!(
+    type_name: StringArray,
+    element_type: GodotString,
+    opaque_type: OpaquePackedStringArray,
+    inner_type: "PackedStringArray",
+    construct_default: packed_string_array_construct_default,
+    construct_copy: packed_string_array_construct_copy,
+    destroy: packed_string_array_destroy,
+    operator_equal: packed_string_array_operator_equal,
+    operator_index: packed_string_array_operator_index,
+    operator_index_const: packed_string_array_operator_index_const,
+    size: packed_string_array_size,
+    resize: packed_string_array_resize,
+);
+
+impl_packed_array!(
+    type_name: Vector2Array,
+    element_type: Vector2,
+    opaque_type: OpaquePackedVector2Array,
+    inner_type: "PackedVector2Array",
+    construct_default: packed_vector2_array_construct_default,
+    construct_copy: packed_vector2_array_construct_copy,
+    destroy: packed_vector2_array_destroy,
+    operator_equal: packed_vector2_array_operator_equal,
+    operator_index: packed_vector2_array_operator_index,
+    operator_index_const: packed_vector2_array_operator_index_const,
+    size: packed_vector2_array_size,
+    resize: packed_vector2_array_resize,
+);
+
+impl_packed_array!(
+    type_name: Vector3Array,
+    element_type: Vector3,
+    opaque_type: OpaquePackedVector3Array,
+    inner_type: "PackedVector3Array",
+    construct_default: packed_vector3_array_construct_default,
+    construct_copy: packed_vector3_array_construct_copy,
+    destroy: packed_vector3_array_destroy,
+    operator_equal: packed_vector3_array_operator_equal,
+    operator_index: packed_vector3_array_operator_index,
+    operator_index_const: packed_vector3_array_operator_index_const,
+    size: packed_vector3_array_size,
+    resize: packed_vector3_array_resize,
+);
+
+impl_packed_array!(
+    type_name: ColorArray,
+    element_type: Color,
+    opaque_type: OpaquePackedColorArray,
+    inner_type: "PackedColorArray",
+    construct_default: packed_color_array_construct_default,
+    construct_copy: packed_color_array_construct_copy,
+    destroy: packed_color_array_destroy,
+    operator_equal: packed_color_array_operator_equal,
+    operator_index: packed_color_array_operator_index,
+    operator_index_const: packed_color_array_operator_index_const,
+    size: packed_color_array_size,
+    resize: packed_color_array_resize,
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions from and to Array
+
+impl_builtin_froms!(Array;
+    ByteArray => array_from_packed_byte_array,
+    ColorArray => array_from_packed_color_array,
+    Float32Array => array_from_packed_float32_array,
+    Float64Array => array_from_packed_float64_array,
+    Int32Array => array_from_packed_int32_array,
+    Int64Array => array_from_packed_int64_array,
+    StringArray => array_from_packed_string_array,
+    Vector2Array => array_from_packed_vector2_array,
+    Vector3Array => array_from_packed_vector3_array,
+);
+
+impl_builtin_froms!(ByteArray; Array => packed_byte_array_from_array);
+impl_builtin_froms!(ColorArray; Array => packed_color_array_from_array);
+impl_builtin_froms!(Float32Array; Array => packed_float32_array_from_array);
+impl_builtin_froms!(Float64Array; Array => packed_float64_array_from_array);
+impl_builtin_froms!(Int32Array; Array => packed_int32_array_from_array);
+impl_builtin_froms!(Int64Array; Array => packed_int64_array_from_array);
+impl_builtin_froms!(StringArray; Array => packed_string_array_from_array);
+impl_builtin_froms!(Vector2Array; Array => packed_vector2_array_from_array);
+impl_builtin_froms!(Vector3Array; Array => packed_vector3_array_from_array);
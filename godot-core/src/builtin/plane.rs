@@ -0,0 +1,139 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{math, Vector3};
+
+/// Plane in 3D space, in Hessian normal form.
+///
+/// Consists of all points `p` with `normal.dot(p) == d`. Most operations assume `normal` to be normalized.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub d: f32,
+}
+
+impl Plane {
+    pub const fn new(normal: Vector3, d: f32) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates a plane with the given `normal`, passing through `point`.
+    pub fn from_normal_point(normal: Vector3, point: Vector3) -> Self {
+        Self::new(normal, normal.dot(point))
+    }
+
+    /// Creates a plane through three points. The normal faces towards the side where the points appear clockwise.
+    pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        let normal = (a - c).cross(a - b).normalized();
+        Self::from_normal_point(normal, a)
+    }
+
+    /// Returns an equivalent plane with normalized `normal`. A zero normal yields the default plane.
+    pub fn normalized(self) -> Self {
+        let length = self.normal.length();
+        if length == 0.0 {
+            Self::default()
+        } else {
+            Self::new(self.normal / length, self.d / length)
+        }
+    }
+
+    /// Point on the plane closest to the origin.
+    pub fn center(self) -> Vector3 {
+        self.normal * self.d
+    }
+
+    /// Signed distance from the plane to `point`; positive if `point` lies on the side the normal faces.
+    pub fn distance_to(self, point: Vector3) -> f32 {
+        self.normal.dot(point) - self.d
+    }
+
+    /// Returns `true` if `point` lies on the side the normal faces.
+    pub fn is_point_over(self, point: Vector3) -> bool {
+        self.normal.dot(point) > self.d
+    }
+
+    /// Returns `true` if `point` lies within `tolerance` of the plane.
+    pub fn has_point(self, point: Vector3, tolerance: f32) -> bool {
+        self.distance_to(point).abs() <= tolerance
+    }
+
+    /// Orthogonally projects `point` onto the plane.
+    pub fn project(self, point: Vector3) -> Vector3 {
+        point - self.normal * self.distance_to(point)
+    }
+
+    /// Returns the single point shared by `self`, `b` and `c`, or `None` if there is no such point.
+    pub fn intersect_3(self, b: Self, c: Self) -> Option<Vector3> {
+        let denom = self.normal.cross(b.normal).dot(c.normal);
+        if math::is_zero_approx(denom) {
+            return None;
+        }
+
+        let result = b.normal.cross(c.normal) * self.d
+            + c.normal.cross(self.normal) * b.d
+            + self.normal.cross(b.normal) * c.d;
+
+        Some(result / denom)
+    }
+
+    /// Returns the point where the ray starting at `from` in `direction` hits the plane, or `None` if it misses.
+    pub fn intersects_ray(self, from: Vector3, direction: Vector3) -> Option<Vector3> {
+        let den = self.normal.dot(direction);
+        if math::is_zero_approx(den) {
+            return None;
+        }
+
+        let dist = (self.normal.dot(from) - self.d) / den;
+        if dist > math::CMP_EPSILON {
+            // Intersection lies behind the ray origin
+            return None;
+        }
+
+        Some(from - direction * dist)
+    }
+
+    /// Returns the point where the segment from `from` to `to` crosses the plane, or `None` if it does not.
+    pub fn intersects_segment(self, from: Vector3, to: Vector3) -> Option<Vector3> {
+        let segment = from - to;
+        let den = self.normal.dot(segment);
+        if math::is_zero_approx(den) {
+            return None;
+        }
+
+        let dist = (self.normal.dot(from) - self.d) / den;
+        if !(-math::CMP_EPSILON..=1.0 + math::CMP_EPSILON).contains(&dist) {
+            return None;
+        }
+
+        Some(from - segment * dist)
+    }
+
+    /// Returns `true` if normal and distance are approximately equal, see [`math::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.normal.is_equal_approx(other.normal) && math::is_equal_approx(self.d, other.d)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.normal.is_finite() && self.d.is_finite()
+    }
+}
+
+impl std::ops::Neg for Plane {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.normal, -self.d)
+    }
+}
+
+impl GodotFfi for Plane {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
@@ -0,0 +1,297 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Plane, Transform3D, Vector2, Vector3, Vector4};
+
+type Glam = glam::f32::Mat4;
+
+/// 4x4 matrix for 3D projective transformations, e.g. a camera's projection.
+///
+/// Stored column-major like in Godot, i.e. `cols[i]` is the i-th column.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Projection {
+    pub cols: [Vector4; 4],
+}
+
+impl Projection {
+    /// Identity projection, which leaves vectors unchanged.
+    pub const IDENTITY: Self = Self::from_cols(
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    );
+
+    /// Projection with all components set to zero.
+    pub const ZERO: Self =
+        Self::from_cols(Vector4::ZERO, Vector4::ZERO, Vector4::ZERO, Vector4::ZERO);
+
+    pub const fn from_cols(x: Vector4, y: Vector4, z: Vector4, w: Vector4) -> Self {
+        Self { cols: [x, y, z, w] }
+    }
+
+    /// Embeds an affine transform: the basis becomes the upper-left 3x3 block, `origin` the translation column.
+    pub fn from_transform3d(transform: Transform3D) -> Self {
+        let extend = |v: Vector3, w: f32| Vector4::new(v.x(), v.y(), v.z(), w);
+        let basis = transform.basis;
+
+        Self::from_cols(
+            extend(basis.column(0), 0.0),
+            extend(basis.column(1), 0.0),
+            extend(basis.column(2), 0.0),
+            extend(transform.origin, 1.0),
+        )
+    }
+
+    /// Perspective projection with vertical field of view `fovy` (in degrees).
+    ///
+    /// If `flip_fov` is `true`, `fovy` is interpreted as the horizontal field of view instead.
+    pub fn create_perspective(
+        fovy: f32,
+        aspect: f32,
+        z_near: f32,
+        z_far: f32,
+        flip_fov: bool,
+    ) -> Self {
+        let fovy = if flip_fov {
+            fovy_from_fovx(fovy, 1.0 / aspect)
+        } else {
+            fovy
+        };
+
+        let (sine, cosine) = (fovy / 2.0).to_radians().sin_cos();
+        let delta_z = z_far - z_near;
+        let cotangent = cosine / sine;
+
+        Self::from_cols(
+            Vector4::new(cotangent / aspect, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, cotangent, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, -(z_far + z_near) / delta_z, -1.0),
+            Vector4::new(0.0, 0.0, -2.0 * z_near * z_far / delta_z, 0.0),
+        )
+    }
+
+    /// Orthogonal projection of the box with the given bounds.
+    pub fn create_orthogonal(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        Self::from_cols(
+            Vector4::new(2.0 / (right - left), 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 2.0 / (top - bottom), 0.0, 0.0),
+            Vector4::new(0.0, 0.0, -2.0 / (z_far - z_near), 0.0),
+            Vector4::new(
+                -(right + left) / (right - left),
+                -(top + bottom) / (top - bottom),
+                -(z_far + z_near) / (z_far - z_near),
+                1.0,
+            ),
+        )
+    }
+
+    /// Orthogonal projection centered on the view axis, with height `size`, or width `size` if `flip_fov` is `true`.
+    pub fn create_orthogonal_aspect(
+        size: f32,
+        aspect: f32,
+        z_near: f32,
+        z_far: f32,
+        flip_fov: bool,
+    ) -> Self {
+        let size = if flip_fov { size } else { size * aspect };
+        let (half_width, half_height) = (size / 2.0, size / aspect / 2.0);
+
+        Self::create_orthogonal(
+            -half_width,
+            half_width,
+            -half_height,
+            half_height,
+            z_near,
+            z_far,
+        )
+    }
+
+    /// Perspective projection of the frustum whose near plane has the given bounds.
+    ///
+    /// # Panics
+    /// If `right <= left`, `top <= bottom` or `z_far <= z_near`.
+    pub fn create_frustum(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        assert!(
+            right > left && top > bottom && z_far > z_near,
+            "Projection::create_frustum(): invalid bounds"
+        );
+
+        let x = 2.0 * z_near / (right - left);
+        let y = 2.0 * z_near / (top - bottom);
+        let a = (right + left) / (right - left);
+        let b = (top + bottom) / (top - bottom);
+        let c = -(z_far + z_near) / (z_far - z_near);
+        let d = -2.0 * z_far * z_near / (z_far - z_near);
+
+        Self::from_cols(
+            Vector4::new(x, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, y, 0.0, 0.0),
+            Vector4::new(a, b, c, -1.0),
+            Vector4::new(0.0, 0.0, d, 0.0),
+        )
+    }
+
+    /// Perspective projection whose near plane has height `size`, or width `size` if `flip_fov` is `true`, and is
+    /// shifted by `offset`.
+    pub fn create_frustum_aspect(
+        size: f32,
+        aspect: f32,
+        offset: Vector2,
+        z_near: f32,
+        z_far: f32,
+        flip_fov: bool,
+    ) -> Self {
+        let size = if flip_fov { size } else { size * aspect };
+        let (half_width, half_height) = (size / 2.0, size / aspect / 2.0);
+
+        Self::create_frustum(
+            -half_width + offset.x(),
+            half_width + offset.x(),
+            -half_height + offset.y(),
+            half_height + offset.y(),
+            z_near,
+            z_far,
+        )
+    }
+
+    /// Returns the 6 clipping planes of this projection's frustum, transformed by `transform`.
+    ///
+    /// Order: near, far, left, top, right, bottom. All normals point outwards.
+    pub fn get_projection_planes(self, transform: Transform3D) -> [Plane; 6] {
+        let m = self.to_glam().to_cols_array();
+
+        // Each plane is given as (a, b, c, d) with a*x + b*y + c*z + d = 0, positive inside the frustum
+        let make_plane = |sign: f32, offset: usize| {
+            let normal = -Vector3::new(
+                m[3] + sign * m[offset],
+                m[7] + sign * m[offset + 4],
+                m[11] + sign * m[offset + 8],
+            );
+            let plane = Plane::new(normal, m[15] + sign * m[offset + 12]).normalized();
+            transform.xform_plane(plane)
+        };
+
+        [
+            make_plane(1.0, 2),
+            make_plane(-1.0, 2),
+            make_plane(1.0, 0),
+            make_plane(-1.0, 1),
+            make_plane(-1.0, 0),
+            make_plane(1.0, 1),
+        ]
+    }
+
+    /// Distance of the near clipping plane.
+    pub fn get_z_near(self) -> f32 {
+        let m = self.to_glam().to_cols_array();
+        let normal = Vector3::new(m[3] + m[2], m[7] + m[6], m[11] + m[10]);
+
+        Plane::new(normal, -m[15] - m[14]).normalized().d
+    }
+
+    /// Distance of the far clipping plane.
+    pub fn get_z_far(self) -> f32 {
+        let m = self.to_glam().to_cols_array();
+        let normal = -Vector3::new(m[3] - m[2], m[7] - m[6], m[11] - m[10]);
+
+        Plane::new(normal, m[15] - m[14]).normalized().d
+    }
+
+    /// Returns `true` if this is an orthogonal (as opposed to perspective) projection.
+    pub fn is_orthogonal(self) -> bool {
+        self.cols[3].w() == 1.0
+    }
+
+    pub fn determinant(self) -> f32 {
+        self.to_glam().determinant()
+    }
+
+    /// Inverse matrix. The determinant must not be zero.
+    pub fn inverse(self) -> Self {
+        Self::from_glam(self.to_glam().inverse())
+    }
+
+    /// Returns `true` if all columns are approximately equal, see [`Vector4::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.cols
+            .iter()
+            .zip(other.cols.iter())
+            .all(|(a, b)| a.is_equal_approx(*b))
+    }
+
+    fn to_glam(self) -> Glam {
+        let [x, y, z, w] = self.cols;
+        Glam::from_cols(x.inner(), y.inner(), z.inner(), w.inner())
+    }
+
+    fn from_glam(glam: Glam) -> Self {
+        Self::from_cols(
+            Vector4::from_inner(glam.x_axis),
+            Vector4::from_inner(glam.y_axis),
+            Vector4::from_inner(glam.z_axis),
+            Vector4::from_inner(glam.w_axis),
+        )
+    }
+}
+
+impl Default for Projection {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl std::ops::Mul for Projection {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_glam(self.to_glam() * rhs.to_glam())
+    }
+}
+
+impl std::ops::MulAssign for Projection {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::Mul<Vector4> for Projection {
+    type Output = Vector4;
+
+    fn mul(self, rhs: Vector4) -> Vector4 {
+        Vector4::from_inner(self.to_glam() * rhs.inner())
+    }
+}
+
+impl GodotFfi for Projection {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+/// Converts a horizontal field of view to a vertical one, both in degrees.
+fn fovy_from_fovx(fovx: f32, aspect: f32) -> f32 {
+    ((fovx.to_radians() * 0.5).tan() * aspect)
+        .atan()
+        .to_degrees()
+        * 2.0
+}
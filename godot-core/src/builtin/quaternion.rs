@@ -0,0 +1,185 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{math, Basis, EulerOrder, Vector3};
+
+type Inner = glam::f32::Quat;
+
+/// Unit quaternion representing a 3D rotation.
+///
+/// Most operations assume the quaternion to be normalized, as in Godot.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Quaternion {
+    inner: Inner,
+}
+
+impl Quaternion {
+    /// Identity rotation `(0, 0, 0, 1)`.
+    pub const IDENTITY: Self = Self::from_inner(Inner::IDENTITY);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self::from_inner(Inner::from_xyzw(x, y, z, w))
+    }
+
+    pub const fn from_inner(inner: Inner) -> Self {
+        Self { inner }
+    }
+
+    /// Rotation by `angle` radians around the normalized `axis`.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        Self::from_inner(Inner::from_axis_angle(axis.inner(), angle))
+    }
+
+    /// Rotation from Euler angles in radians, applied in Godot's default order [`EulerOrder::YXZ`].
+    pub fn from_euler(euler: Vector3) -> Self {
+        let rotation = Inner::from_rotation_y(euler.y())
+            * Inner::from_rotation_x(euler.x())
+            * Inner::from_rotation_z(euler.z());
+
+        Self::from_inner(rotation)
+    }
+
+    /// Extracts the rotation of `basis`, which must be orthonormal.
+    pub fn from_basis(basis: Basis) -> Self {
+        Self::from_inner(Inner::from_mat3(&basis.to_glam()))
+    }
+
+    /// only for testing
+    pub fn inner(self) -> Inner {
+        self.inner
+    }
+
+    pub fn x(self) -> f32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> f32 {
+        self.inner.y
+    }
+
+    pub fn z(self) -> f32 {
+        self.inner.z
+    }
+
+    pub fn w(self) -> f32 {
+        self.inner.w
+    }
+
+    /// Euler angles in radians, in Godot's default order [`EulerOrder::YXZ`].
+    pub fn get_euler(self) -> Vector3 {
+        Basis::from_quaternion(self).get_euler(EulerOrder::YXZ)
+    }
+
+    /// Rotation axis; only meaningful if the quaternion is normalized and not the identity.
+    pub fn get_axis(self) -> Vector3 {
+        let Inner { x, y, z, w } = self.inner;
+        if w.abs() > 1.0 - math::CMP_EPSILON {
+            return Vector3::new(x, y, z);
+        }
+
+        let r = 1.0 / (1.0 - w * w).sqrt();
+        Vector3::new(x * r, y * r, z * r)
+    }
+
+    /// Rotation angle in radians.
+    pub fn get_angle(self) -> f32 {
+        2.0 * self.inner.w.acos()
+    }
+
+    pub fn length(self) -> f32 {
+        self.inner.length()
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.inner.length_squared()
+    }
+
+    pub fn normalized(self) -> Self {
+        Self::from_inner(self.inner.normalize())
+    }
+
+    pub fn is_normalized(self) -> bool {
+        self.inner.is_normalized()
+    }
+
+    /// Inverse rotation. The quaternion must be normalized.
+    pub fn inverse(self) -> Self {
+        Self::from_inner(self.inner.inverse())
+    }
+
+    pub fn dot(self, other: Self) -> f32 {
+        self.inner.dot(other.inner)
+    }
+
+    /// Angle in radians between the rotations `self` and `to`.
+    pub fn angle_to(self, to: Self) -> f32 {
+        let dot = self.dot(to);
+        (dot * dot * 2.0 - 1.0).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Spherical linear interpolation towards `to`, along the shortest path. Both quaternions must be normalized.
+    pub fn slerp(self, to: Self, weight: f32) -> Self {
+        Self::from_inner(self.inner.slerp(to.inner, weight))
+    }
+
+    /// Returns `true` if all components are approximately equal, see [`math::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        math::is_equal_approx(self.inner.x, other.inner.x)
+            && math::is_equal_approx(self.inner.y, other.inner.y)
+            && math::is_equal_approx(self.inner.z, other.inner.z)
+            && math::is_equal_approx(self.inner.w, other.inner.w)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.inner.is_finite()
+    }
+}
+
+/// Composes two rotations; `a * b` applies `b` first, then `a`.
+impl std::ops::Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_inner(self.inner * rhs.inner)
+    }
+}
+
+impl std::ops::MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Rotates a vector. The quaternion must be normalized.
+impl std::ops::Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        Vector3::from_inner(self.inner * rhs.inner())
+    }
+}
+
+impl std::ops::Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_inner(-self.inner)
+    }
+}
+
+impl GodotFfi for Quaternion {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+impl std::fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -0,0 +1,321 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Vector2, Vector2i};
+use crate::engine::global::Side;
+
+/// 2D axis-aligned bounding box, with float coordinates.
+///
+/// Most operations assume a non-negative `size`; use [`Rect2::abs()`] to normalize a rectangle first.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Rect2 {
+    pub position: Vector2,
+    pub size: Vector2,
+}
+
+impl Rect2 {
+    pub const fn new(position: Vector2, size: Vector2) -> Self {
+        Self { position, size }
+    }
+
+    pub const fn from_components(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self::new(Vector2::new(x, y), Vector2::new(width, height))
+    }
+
+    /// Converts an integer rectangle to floats.
+    pub fn from_rect2i(rect: Rect2i) -> Self {
+        Self::new(
+            Vector2::from_vector2i(rect.position),
+            Vector2::from_vector2i(rect.size),
+        )
+    }
+
+    /// Bottom-right corner, i.e. `position + size`.
+    pub fn end(self) -> Vector2 {
+        self.position + self.size
+    }
+
+    /// Moves the bottom-right corner to `end`, keeping `position`.
+    pub fn set_end(&mut self, end: Vector2) {
+        self.size = end - self.position;
+    }
+
+    pub fn center(self) -> Vector2 {
+        self.position + self.size * 0.5
+    }
+
+    pub fn area(self) -> f32 {
+        self.size.x() * self.size.y()
+    }
+
+    /// Returns `true` if both width and height are positive.
+    pub fn has_area(self) -> bool {
+        self.size.x() > 0.0 && self.size.y() > 0.0
+    }
+
+    /// Returns `true` if `point` lies inside the rectangle. The right and bottom edges are excluded.
+    pub fn has_point(self, point: Vector2) -> bool {
+        let end = self.end();
+        point.x() >= self.position.x()
+            && point.y() >= self.position.y()
+            && point.x() < end.x()
+            && point.y() < end.y()
+    }
+
+    /// Returns `true` if the two rectangles overlap.
+    ///
+    /// If `include_borders` is `true`, rectangles which only touch at their edges also count as overlapping.
+    pub fn intersects(self, other: Self, include_borders: bool) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        if include_borders {
+            self.position.x() <= other_end.x()
+                && end.x() >= other.position.x()
+                && self.position.y() <= other_end.y()
+                && end.y() >= other.position.y()
+        } else {
+            self.position.x() < other_end.x()
+                && end.x() > other.position.x()
+                && self.position.y() < other_end.y()
+                && end.y() > other.position.y()
+        }
+    }
+
+    /// Returns the overlapping area of both rectangles, or `None` if they do not intersect.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        if !self.intersects(other, false) {
+            return None;
+        }
+
+        let position = self.position.max(other.position);
+        let end = self.end().min(other.end());
+        Some(Self::new(position, end - position))
+    }
+
+    /// Returns the smallest rectangle enclosing both `self` and `other`.
+    pub fn merge(self, other: Self) -> Self {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Self::new(position, end - position)
+    }
+
+    /// Returns `true` if `other` lies completely inside `self`.
+    pub fn encloses(self, other: Self) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        other.position.x() >= self.position.x()
+            && other.position.y() >= self.position.y()
+            && other_end.x() <= end.x()
+            && other_end.y() <= end.y()
+    }
+
+    /// Returns the rectangle extended by `amount` on all four sides. Negative values shrink it.
+    pub fn grow(self, amount: f32) -> Self {
+        self.grow_individual(amount, amount, amount, amount)
+    }
+
+    /// Returns the rectangle extended by `amount` on the given `side`.
+    ///
+    /// # Panics
+    /// If `side` is not one of the four `Side` constants, e.g. when created from an invalid ordinal.
+    pub fn grow_side(self, side: Side, amount: f32) -> Self {
+        match side {
+            Side::SIDE_LEFT => self.grow_individual(amount, 0.0, 0.0, 0.0),
+            Side::SIDE_TOP => self.grow_individual(0.0, amount, 0.0, 0.0),
+            Side::SIDE_RIGHT => self.grow_individual(0.0, 0.0, amount, 0.0),
+            Side::SIDE_BOTTOM => self.grow_individual(0.0, 0.0, 0.0, amount),
+            _ => panic!("invalid side {side:?}"),
+        }
+    }
+
+    /// Returns the rectangle extended by a separate amount on each side.
+    pub fn grow_individual(self, left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self::new(
+            self.position - Vector2::new(left, top),
+            self.size + Vector2::new(left + right, top + bottom),
+        )
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `point`.
+    pub fn expand(self, point: Vector2) -> Self {
+        let position = self.position.min(point);
+        let end = self.end().max(point);
+        Self::new(position, end - position)
+    }
+
+    /// Returns an equivalent rectangle with non-negative size, moving `position` to the top-left corner.
+    pub fn abs(self) -> Self {
+        Self::new(
+            self.position + self.size.min(Vector2::ZERO),
+            self.size.abs(),
+        )
+    }
+
+    /// Returns `true` if position and size are approximately equal, see [`Vector2::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.position.is_equal_approx(other.position) && self.size.is_equal_approx(other.size)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.position.is_finite() && self.size.is_finite()
+    }
+}
+
+impl GodotFfi for Rect2 {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// 2D axis-aligned bounding box, with integer coordinates.
+///
+/// Most operations assume a non-negative `size`; use [`Rect2i::abs()`] to normalize a rectangle first.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub struct Rect2i {
+    pub position: Vector2i,
+    pub size: Vector2i,
+}
+
+impl Rect2i {
+    pub const fn new(position: Vector2i, size: Vector2i) -> Self {
+        Self { position, size }
+    }
+
+    pub const fn from_components(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self::new(Vector2i::new(x, y), Vector2i::new(width, height))
+    }
+
+    /// Converts a float rectangle, truncating position and size towards zero (like Godot's `Rect2i(Rect2)`).
+    pub fn from_rect2(rect: Rect2) -> Self {
+        Self::new(
+            Vector2i::from_vector2(rect.position),
+            Vector2i::from_vector2(rect.size),
+        )
+    }
+
+    /// Converts to the float rectangle [`Rect2`].
+    pub fn to_rect2(self) -> Rect2 {
+        Rect2::from_rect2i(self)
+    }
+
+    /// Bottom-right corner, i.e. `position + size`.
+    pub fn end(self) -> Vector2i {
+        self.position + self.size
+    }
+
+    /// Moves the bottom-right corner to `end`, keeping `position`.
+    pub fn set_end(&mut self, end: Vector2i) {
+        self.size = end - self.position;
+    }
+
+    /// Center of the rectangle, rounded towards `position` for odd sizes.
+    pub fn center(self) -> Vector2i {
+        self.position + self.size / 2
+    }
+
+    pub fn area(self) -> i32 {
+        self.size.x() * self.size.y()
+    }
+
+    /// Returns `true` if both width and height are positive.
+    pub fn has_area(self) -> bool {
+        self.size.x() > 0 && self.size.y() > 0
+    }
+
+    /// Returns `true` if `point` lies inside the rectangle. The right and bottom edges are excluded.
+    pub fn has_point(self, point: Vector2i) -> bool {
+        let end = self.end();
+        point.x() >= self.position.x()
+            && point.y() >= self.position.y()
+            && point.x() < end.x()
+            && point.y() < end.y()
+    }
+
+    /// Returns `true` if the two rectangles overlap. Rectangles which only touch at their edges do not.
+    pub fn intersects(self, other: Self) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        self.position.x() < other_end.x()
+            && end.x() > other.position.x()
+            && self.position.y() < other_end.y()
+            && end.y() > other.position.y()
+    }
+
+    /// Returns the overlapping area of both rectangles, or `None` if they do not intersect.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let position = self.position.max(other.position);
+        let end = self.end().min(other.end());
+        Some(Self::new(position, end - position))
+    }
+
+    /// Returns the smallest rectangle enclosing both `self` and `other`.
+    pub fn merge(self, other: Self) -> Self {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Self::new(position, end - position)
+    }
+
+    /// Returns `true` if `other` lies completely inside `self`.
+    pub fn encloses(self, other: Self) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        other.position.x() >= self.position.x()
+            && other.position.y() >= self.position.y()
+            && other_end.x() <= end.x()
+            && other_end.y() <= end.y()
+    }
+
+    /// Returns the rectangle extended by `amount` on all four sides. Negative values shrink it.
+    pub fn grow(self, amount: i32) -> Self {
+        self.grow_individual(amount, amount, amount, amount)
+    }
+
+    /// Returns the rectangle extended by `amount` on the given `side`.
+    ///
+    /// # Panics
+    /// If `side` is not one of the four `Side` constants, e.g. when created from an invalid ordinal.
+    pub fn grow_side(self, side: Side, amount: i32) -> Self {
+        match side {
+            Side::SIDE_LEFT => self.grow_individual(amount, 0, 0, 0),
+            Side::SIDE_TOP => self.grow_individual(0, amount, 0, 0),
+            Side::SIDE_RIGHT => self.grow_individual(0, 0, amount, 0),
+            Side::SIDE_BOTTOM => self.grow_individual(0, 0, 0, amount),
+            _ => panic!("invalid side {side:?}"),
+        }
+    }
+
+    /// Returns the rectangle extended by a separate amount on each side.
+    pub fn grow_individual(self, left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self::new(
+            self.position - Vector2i::new(left, top),
+            self.size + Vector2i::new(left + right, top + bottom),
+        )
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `point`.
+    pub fn expand(self, point: Vector2i) -> Self {
+        let position = self.position.min(point);
+        let end = self.end().max(point);
+        Self::new(position, end - position)
+    }
+
+    /// Returns an equivalent rectangle with non-negative size, moving `position` to the top-left corner.
+    pub fn abs(self) -> Self {
+        Self::new(
+            self.position + self.size.min(Vector2i::ZERO),
+            self.size.abs(),
+        )
+    }
+}
+
+impl GodotFfi for Rect2i {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::num::NonZeroU64;
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+/// A handle to a resource managed by a server, such as `RenderingServer` or `PhysicsServer2D`.
+///
+/// An RID is an opaque 64-bit ID. The ID 0 is reserved for the invalid RID, which servers use to signal "no resource";
+/// engine methods returning RIDs map it to `None`.
+///
+/// RIDs are ordered and hashed by their ID, so they can be used as keys in maps and sets.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Rid {
+    // Same layout as u64, with 0 representing None
+    id: Option<NonZeroU64>,
+}
+
+impl Rid {
+    /// The invalid RID, equivalent to `RID()` in GDScript.
+    pub const INVALID: Rid = Rid { id: None };
+
+    /// Creates an RID from its ID. An ID of 0 creates [`Rid::INVALID`].
+    ///
+    /// This is only meaningful for IDs previously obtained from [`Rid::get_id()`].
+    pub const fn new(id: u64) -> Self {
+        Self {
+            id: NonZeroU64::new(id),
+        }
+    }
+
+    /// Returns the ID, or 0 if invalid.
+    pub fn get_id(self) -> u64 {
+        self.id.map_or(0, NonZeroU64::get)
+    }
+
+    /// Returns `true` unless this is [`Rid::INVALID`].
+    ///
+    /// Note that this does not check whether a server still holds a resource for this RID.
+    pub fn is_valid(self) -> bool {
+        self.id.is_some()
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn from_sys_init_opt(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Option<Self> {
+        let rid = Self::from_sys_init(init_fn);
+        rid.is_valid().then_some(rid)
+    }
+}
+
+impl GodotFfi for Rid {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+impl Default for Rid {
+    fn default() -> Self {
+        Self::INVALID
+    }
+}
+
+impl fmt::Debug for Rid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.id {
+            Some(id) => write!(f, "Rid({id})"),
+            None => write!(f, "Rid::INVALID"),
+        }
+    }
+}
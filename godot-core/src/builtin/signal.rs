@@ -0,0 +1,137 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use godot_ffi as sys;
+use sys::types::OpaqueSignal;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Array, Callable, StringName, ToVariant, Variant};
+use crate::engine::global::Error;
+use crate::engine::object::ConnectFlags;
+use crate::engine::Object;
+use crate::obj::{EngineEnum, Gd, GodotClass, InstanceId};
+
+/// A signal of a specific object, which can be passed around as a value.
+///
+/// Equivalent to GDScript's `object.signal_name` expressions, which can be connected, emitted and awaited.
+#[repr(C)]
+pub struct Signal {
+    opaque: OpaqueSignal,
+}
+
+impl Signal {
+    /// Creates a value referring to the signal named `name` on `object`.
+    ///
+    /// The signal is not checked for existence; see [`Object::has_signal()`].
+    pub fn new<T, S>(object: Gd<T>, name: S) -> Self
+    where
+        T: GodotClass, // + Inherits<Object>,
+        S: Into<StringName>,
+    {
+        let name = name.into();
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::method_table().signal_from_object_signal;
+                let args = [object.sys(), name.sys()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+
+    /// Emits the signal, calling all connected callables with `args`.
+    pub fn emit(&self, args: &[Variant]) {
+        self.to_variant().call_panicking("emit", args);
+    }
+
+    /// Connects `callable` to this signal, optionally with `flags` such as [`ConnectFlags::DEFERRED`].
+    ///
+    /// Returns an error if the connection already exists (unless [`ConnectFlags::REFERENCE_COUNTED`] is passed)
+    /// or the signal does not exist.
+    pub fn connect(&self, callable: &Callable, flags: Option<ConnectFlags>) -> Error {
+        let flags = flags.map_or(0, |flags| i64::from(flags.ord()));
+        let error = unsafe {
+            builtin_call!(signal_connect(self.sys(), callable.sys(), flags.sys()) -> i64)
+        };
+        Error::from_ord(error as i32)
+    }
+
+    /// Disconnects `callable` from this signal. Godot prints an error if they are not connected.
+    pub fn disconnect(&self, callable: &Callable) {
+        unsafe { builtin_call!(signal_disconnect(self.sys(), callable.sys())) }
+    }
+
+    pub fn is_connected(&self, callable: &Callable) -> bool {
+        unsafe { builtin_call!(signal_is_connected(self.sys(), callable.sys()) -> bool) }
+    }
+
+    /// Returns all connections, as dictionaries with keys `signal`, `callable` and `flags`.
+    pub fn get_connections(&self) -> Array {
+        unsafe { builtin_call!(signal_get_connections(self.sys()) -> Array) }
+    }
+
+    /// Returns `true` if this signal has no target, e.g. if it was default-constructed.
+    pub fn is_null(&self) -> bool {
+        unsafe { builtin_call!(signal_is_null(self.sys()) -> bool) }
+    }
+
+    /// Name of the signal.
+    pub fn get_name(&self) -> StringName {
+        unsafe { builtin_call!(signal_get_name(self.sys()) -> StringName) }
+    }
+
+    /// Returns the object emitting the signal, or `None` if there is none or it has been freed.
+    pub fn get_object(&self) -> Option<Gd<Object>> {
+        self.get_object_id().and_then(Gd::try_from_instance_id)
+    }
+
+    /// Returns the instance ID of the object emitting the signal, or `None` if there is none.
+    pub fn get_object_id(&self) -> Option<InstanceId> {
+        let id = unsafe { builtin_call!(signal_get_object_id(self.sys()) -> i64) };
+        InstanceId::try_from_i64(id)
+    }
+}
+
+impl GodotFfi for Signal {
+    ffi_methods! {
+        type sys::GDNativeTypePtr = *mut Opaque;
+        fn sys;
+        fn write_sys;
+    }
+
+    impl_from_sys_as_copy!(from_sys => signal_construct_copy);
+
+    unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
+        // Can't use uninitialized pointer -- Signal implementation in C++ expects that on assignment,
+        // the target type is a valid signal (possibly null)
+
+        let mut result = Self::default();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_traits_as_sys! {
+    for Signal {
+        Default => signal_construct_default;
+        Clone => signal_construct_copy;
+        Drop => signal_destroy;
+        Eq => signal_operator_equal;
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Signal({})", self.to_variant())
+    }
+}
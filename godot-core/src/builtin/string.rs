@@ -4,12 +4,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Range};
 use std::{convert::Infallible, fmt, str::FromStr};
 
 use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
+use crate::builtin::{Dictionary, StringArray, ToVariant};
+
 #[repr(C, align(8))]
 pub struct GodotString {
     opaque: OpaqueString,
@@ -20,6 +24,141 @@ impl GodotString {
         Self::default()
     }
 
+    /// Number of characters (Unicode code points).
+    pub fn len(&self) -> usize {
+        let len = unsafe { builtin_call!(string_length(self.sys()) -> i64) };
+        len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Borrows the characters from Godot's UTF-32 buffer, without copying them.
+    ///
+    /// # Panics
+    /// If the string contains code points that are not valid `char`s, e.g. unpaired surrogates.
+    pub fn chars(&self) -> &[char] {
+        let code_points = self.code_points();
+        assert!(
+            code_points.iter().all(|&c| char::from_u32(c).is_some()),
+            "GodotString contains code points which are not valid Unicode scalar values"
+        );
+
+        // SAFETY: char has the same layout as u32, and all values were checked above
+        unsafe {
+            std::slice::from_raw_parts(code_points.as_ptr() as *const char, code_points.len())
+        }
+    }
+
+    /// Borrows Godot's UTF-32 buffer as raw code points, which may include values that are not valid `char`s.
+    fn code_points(&self) -> &[u32] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+
+        unsafe {
+            let data = interface_fn!(string_operator_index_const)(self.string_sys(), 0);
+            std::slice::from_raw_parts(data, len)
+        }
+    }
+
+    /// Encodes the string as UTF-16.
+    pub fn to_utf16(&self) -> Vec<u16> {
+        unsafe {
+            let len =
+                interface_fn!(string_to_utf16_chars)(self.string_sys(), std::ptr::null_mut(), 0);
+
+            assert!(len >= 0);
+            let mut buf = vec![0u16; len as usize];
+            interface_fn!(string_to_utf16_chars)(self.string_sys(), buf.as_mut_ptr(), len);
+
+            buf
+        }
+    }
+
+    /// Decodes a UTF-16 string. Invalid sequences are replaced by Godot.
+    pub fn from_utf16(utf16: &[u16]) -> Self {
+        unsafe {
+            Self::from_string_sys_init(|string_ptr| {
+                let ctor = interface_fn!(string_new_with_utf16_chars_and_len);
+                ctor(string_ptr, utf16.as_ptr(), utf16.len() as i64);
+            })
+        }
+    }
+
+    /// Returns the character index of the first occurrence of `what`, or `None` if there is none.
+    pub fn find(&self, what: impl Into<GodotString>) -> Option<usize> {
+        let what = what.into();
+        let from = 0i64;
+        let index =
+            unsafe { builtin_call!(string_find(self.sys(), what.sys(), from.sys()) -> i64) };
+
+        // Godot returns -1 if not found
+        (index >= 0).then_some(index as usize)
+    }
+
+    /// Returns `true` if `what` occurs in this string.
+    pub fn contains(&self, what: impl Into<GodotString>) -> bool {
+        self.find(what).is_some()
+    }
+
+    pub fn begins_with(&self, prefix: impl Into<GodotString>) -> bool {
+        let prefix = prefix.into();
+        unsafe { builtin_call!(string_begins_with(self.sys(), prefix.sys()) -> bool) }
+    }
+
+    pub fn ends_with(&self, suffix: impl Into<GodotString>) -> bool {
+        let suffix = suffix.into();
+        unsafe { builtin_call!(string_ends_with(self.sys(), suffix.sys()) -> bool) }
+    }
+
+    /// Splits the string at each occurrence of `delimiter`. Empty parts are included.
+    pub fn split(&self, delimiter: impl Into<GodotString>) -> StringArray {
+        let delimiter = delimiter.into();
+        let allow_empty = true;
+        let max_split = 0i64; // unlimited
+
+        unsafe {
+            builtin_call!(string_split(
+                self.sys(),
+                delimiter.sys(),
+                allow_empty.sys(),
+                max_split.sys(),
+            ) -> StringArray)
+        }
+    }
+
+    /// Returns the characters in `range` as a new string. The range is clamped to the string's bounds.
+    pub fn substr(&self, range: Range<usize>) -> Self {
+        let from = range.start as i64;
+        let len = range.end.saturating_sub(range.start) as i64;
+
+        unsafe { builtin_call!(string_substr(self.sys(), from.sys(), len.sys()) -> Self) }
+    }
+
+    pub fn to_lower(&self) -> Self {
+        unsafe { builtin_call!(string_to_lower(self.sys()) -> Self) }
+    }
+
+    pub fn to_upper(&self) -> Self {
+        unsafe { builtin_call!(string_to_upper(self.sys()) -> Self) }
+    }
+
+    /// Replaces each placeholder `{key}` with the corresponding value in `values`.
+    ///
+    /// ```ignore
+    /// let values: Dictionary = [(GodotString::from("name"), GodotString::from("Godot"))].into_iter().collect();
+    /// assert_eq!(GodotString::from("Hello {name}!").format(&values), GodotString::from("Hello Godot!"));
+    /// ```
+    pub fn format(&self, values: &Dictionary) -> Self {
+        let values = values.to_variant();
+        let placeholder = GodotString::from("{_}");
+
+        unsafe { builtin_call!(string_format(self.sys(), values.sys(), placeholder.sys()) -> Self) }
+    }
+
     fn from_opaque(opaque: OpaqueString) -> Self {
         Self { opaque }
     }
@@ -44,11 +183,12 @@ impl GodotString {
 impl GodotFfi for GodotString {
     ffi_methods! {
         type sys::GDNativeTypePtr = *mut Opaque;
-        fn from_sys;
         fn sys;
         fn write_sys;
     }
 
+    impl_from_sys_as_copy!(from_sys => string_construct_copy);
+
     unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
         // Can't use uninitialized pointer -- String CoW implementation in C++ expects that on assignment,
         // the target CoW pointer is either initialized or nullptr
@@ -100,6 +240,12 @@ impl From<String> for GodotString {
     }
 }
 
+impl From<&GodotString> for GodotString {
+    fn from(s: &GodotString) -> GodotString {
+        s.clone()
+    }
+}
+
 impl From<&str> for GodotString {
     fn from(val: &str) -> Self {
         GodotString::from_str(val).expect("From<&str>")
@@ -165,6 +311,39 @@ impl_traits_as_sys! {
     }
 }
 
+impl Hash for GodotString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash = unsafe { builtin_call!(string_hash(self.sys()) -> i64) };
+        state.write_i64(hash)
+    }
+}
+
+impl Add<&GodotString> for GodotString {
+    type Output = GodotString;
+
+    fn add(mut self, rhs: &GodotString) -> GodotString {
+        self += rhs;
+        self
+    }
+}
+
+impl AddAssign<&GodotString> for GodotString {
+    fn add_assign(&mut self, rhs: &GodotString) {
+        // Operate on raw code points, so that strings with unpaired surrogates can be concatenated as well
+        let (lhs, rhs) = (self.code_points(), rhs.code_points());
+        let mut code_points = Vec::with_capacity(lhs.len() + rhs.len());
+        code_points.extend_from_slice(lhs);
+        code_points.extend_from_slice(rhs);
+
+        *self = unsafe {
+            Self::from_string_sys_init(|string_ptr| {
+                let ctor = interface_fn!(string_new_with_utf32_chars_and_len);
+                ctor(string_ptr, code_points.as_ptr(), code_points.len() as i64);
+            })
+        };
+    }
+}
+
 impl Drop for GodotString {
     fn drop(&mut self) {
         unsafe {
@@ -8,8 +8,17 @@ use crate::builtin::GodotString;
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
-
+use std::hash::{Hash, Hasher};
+
+/// Godot's interned string type.
+///
+/// All `StringName`s with the same content share one allocation, so equality, ordering and hashing only compare
+/// that pointer and are O(1). Creating a `StringName` from a string, on the other hand, requires a lookup in Godot's
+/// global table; in hot paths, prefer [`static_name!`] to create it only once.
+///
+/// Note that ordering is by identity, not lexicographic, and is not stable across engine runs.
 #[repr(C)]
 pub struct StringName {
     opaque: sys::types::OpaqueStringName,
@@ -35,6 +44,12 @@ impl StringName {
         std::mem::forget(self);
         ptr
     }
+
+    /// Address of the interned string data, which identifies the content. Null for the empty name.
+    fn interned_ptr(&self) -> usize {
+        // StringName consists of a single pointer to its shared data
+        unsafe { std::ptr::read(self.string_sys() as *const usize) }
+    }
 }
 
 impl Drop for StringName {
@@ -45,14 +60,21 @@ impl Drop for StringName {
     }
 }
 
+impl Clone for StringName {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_sys(self.sys()) }
+    }
+}
+
 impl GodotFfi for StringName {
     ffi_methods! {
         type sys::GDNativeTypePtr = *mut Opaque;
-        fn from_sys;
         fn sys;
         fn write_sys;
     }
 
+    impl_from_sys_as_copy!(from_sys => string_name_construct_copy);
+
     unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
         // Can't use uninitialized pointer -- StringName implementation in C++ expects that on assignment,
         // the target type is a valid string (possibly empty)
@@ -97,6 +119,39 @@ impl Debug for StringName {
     }
 }
 
+impl PartialEq for StringName {
+    fn eq(&self, other: &Self) -> bool {
+        self.interned_ptr() == other.interned_ptr()
+    }
+}
+
+impl Eq for StringName {}
+
+impl PartialOrd for StringName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.interned_ptr().cmp(&other.interned_ptr())
+    }
+}
+
+impl Hash for StringName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.interned_ptr().hash(state)
+    }
+}
+
+impl PartialEq<&str> for StringName {
+    fn eq(&self, other: &&str) -> bool {
+        // Compare contents, to avoid interning `other` in Godot's global table
+        GodotString::from(self) == GodotString::from(*other)
+    }
+}
+
 impl From<&GodotString> for StringName {
     fn from(s: &GodotString) -> Self {
         unsafe {
@@ -116,6 +171,30 @@ impl From<&str> for StringName {
     }
 }
 
+impl From<GodotString> for StringName {
+    fn from(s: GodotString) -> Self {
+        Self::from(&s)
+    }
+}
+
+impl From<&String> for StringName {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for StringName {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<&StringName> for StringName {
+    fn from(s: &StringName) -> Self {
+        s.clone()
+    }
+}
+
 impl From<&StringName> for GodotString {
     fn from(s: &StringName) -> Self {
         unsafe {
@@ -127,3 +206,35 @@ impl From<&StringName> for GodotString {
         }
     }
 }
+
+impl From<StringName> for GodotString {
+    fn from(s: StringName) -> Self {
+        Self::from(&s)
+    }
+}
+
+/// Returns a [`StringName`] for a string literal, which is interned only once per thread.
+///
+/// Subsequent evaluations of the same macro invocation only increment a reference count, instead of looking up
+/// the string in Godot's global table. Useful for method and signal names in frequently called code.
+///
+/// ```no_run
+/// use godot::builtin::{static_name, StringName};
+///
+/// let name: StringName = static_name!("ready");
+/// assert_eq!(name, "ready");
+/// ```
+#[macro_export]
+macro_rules! static_name {
+    ($name:literal) => {{
+        std::thread_local! {
+            // Never dropped: thread-locals may be destroyed after the engine has shut down
+            static NAME: std::mem::ManuallyDrop<$crate::builtin::StringName> =
+                std::mem::ManuallyDrop::new($crate::builtin::StringName::from($name));
+        }
+
+        NAME.with(|name| $crate::builtin::StringName::clone(name))
+    }};
+}
+
+pub use crate::static_name;
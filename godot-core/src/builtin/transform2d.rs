@@ -0,0 +1,278 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::f32::consts::FRAC_PI_2;
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{math, Rect2, Vector2};
+
+/// 2D affine transform, consisting of a 2x2 basis (`x` and `y` axes) and an `origin`.
+///
+/// Stored column-wise like in Godot. Operations follow Godot semantics: methods without `_local` suffix
+/// (e.g. [`Transform2D::rotated()`]) operate relative to the parent, i.e. they are applied _after_ `self`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Transform2D {
+    /// The basis' X axis, i.e. the first column.
+    pub x: Vector2,
+
+    /// The basis' Y axis, i.e. the second column.
+    pub y: Vector2,
+
+    /// Translation offset.
+    pub origin: Vector2,
+}
+
+impl Transform2D {
+    /// Identity transform, without translation, rotation or scale.
+    pub const IDENTITY: Self = Self::new(
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+        Vector2::new(0.0, 0.0),
+    );
+
+    /// Transform mirroring along the Y axis, i.e. negating X coordinates.
+    pub const FLIP_X: Self = Self::new(
+        Vector2::new(-1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+        Vector2::new(0.0, 0.0),
+    );
+
+    /// Transform mirroring along the X axis, i.e. negating Y coordinates.
+    pub const FLIP_Y: Self = Self::new(
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, -1.0),
+        Vector2::new(0.0, 0.0),
+    );
+
+    pub const fn new(x: Vector2, y: Vector2, origin: Vector2) -> Self {
+        Self { x, y, origin }
+    }
+
+    /// Rotation by `angle` radians, followed by translation by `origin`.
+    pub fn from_angle_origin(angle: f32, origin: Vector2) -> Self {
+        Self::from_angle_scale_skew_origin(angle, Vector2::ONE, 0.0, origin)
+    }
+
+    /// Combines scale, skew, rotation and translation (applied in this order), like `Node2D` does.
+    pub fn from_angle_scale_skew_origin(
+        angle: f32,
+        scale: Vector2,
+        skew: f32,
+        origin: Vector2,
+    ) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (skew_sin, skew_cos) = (angle + skew).sin_cos();
+
+        Self::new(
+            Vector2::new(cos, sin) * scale.x(),
+            Vector2::new(-skew_sin, skew_cos) * scale.y(),
+            origin,
+        )
+    }
+
+    /// Determinant of the 2x2 basis. Negative if the transform contains a reflection.
+    pub fn determinant(self) -> f32 {
+        self.x.x() * self.y.y() - self.x.y() * self.y.x()
+    }
+
+    /// Rotation in radians, i.e. the angle of the X axis.
+    pub fn get_rotation(self) -> f32 {
+        self.x.y().atan2(self.x.x())
+    }
+
+    /// Length of each axis. The Y component is negated if the transform contains a reflection.
+    pub fn get_scale(self) -> Vector2 {
+        let sign = math::sign(self.determinant());
+        Vector2::new(self.x.length(), self.y.length() * sign)
+    }
+
+    /// Skew in radians, i.e. how far the Y axis deviates from being perpendicular to the X axis.
+    pub fn get_skew(self) -> f32 {
+        let sign = math::sign(self.determinant());
+        let dot = self.x.normalized().dot(self.y.normalized() * sign);
+        dot.acos() - FRAC_PI_2
+    }
+
+    /// Inverse of a transform with orthonormal basis (only rotation and translation). Faster than
+    /// [`Transform2D::affine_inverse()`].
+    pub fn inverse(self) -> Self {
+        let x = Vector2::new(self.x.x(), self.y.x());
+        let y = Vector2::new(self.x.y(), self.y.y());
+        let basis = Self::new(x, y, Vector2::ZERO);
+
+        Self::new(x, y, basis.basis_xform(-self.origin))
+    }
+
+    /// Inverse of any invertible transform, including scale and skew.
+    ///
+    /// Like [`Basis::inverse()`][crate::builtin::Basis::inverse], does not panic for a singular basis (e.g. zero
+    /// scale), but returns non-finite components.
+    pub fn affine_inverse(self) -> Self {
+        let idet = 1.0 / self.determinant();
+        let x = Vector2::new(self.y.y() * idet, -self.x.y() * idet);
+        let y = Vector2::new(-self.y.x() * idet, self.x.x() * idet);
+        let basis = Self::new(x, y, Vector2::ZERO);
+
+        Self::new(x, y, basis.basis_xform(-self.origin))
+    }
+
+    /// Returns the transform with orthogonal, normalized axes (Gram-Schmidt), which removes scale and skew.
+    pub fn orthonormalized(self) -> Self {
+        let x = self.x.normalized();
+        let y = (self.y - x * x.dot(self.y)).normalized();
+        Self::new(x, y, self.origin)
+    }
+
+    /// Rotates the transform by `angle` radians around the parent's origin.
+    pub fn rotated(self, angle: f32) -> Self {
+        Self::from_angle_origin(angle, Vector2::ZERO) * self
+    }
+
+    /// Rotates the transform by `angle` radians in its own local space.
+    pub fn rotated_local(self, angle: f32) -> Self {
+        self * Self::from_angle_origin(angle, Vector2::ZERO)
+    }
+
+    /// Scales the transform (including `origin`) relative to the parent.
+    pub fn scaled(self, scale: Vector2) -> Self {
+        Self::new(self.x * scale, self.y * scale, self.origin * scale)
+    }
+
+    /// Scales the transform's axes in its own local space, keeping `origin`.
+    pub fn scaled_local(self, scale: Vector2) -> Self {
+        Self::new(self.x * scale.x(), self.y * scale.y(), self.origin)
+    }
+
+    /// Translates the transform by `offset`, relative to the parent.
+    pub fn translated(self, offset: Vector2) -> Self {
+        Self::new(self.x, self.y, self.origin + offset)
+    }
+
+    /// Translates the transform by `offset`, in its own local space.
+    pub fn translated_local(self, offset: Vector2) -> Self {
+        Self::new(self.x, self.y, self.origin + self.basis_xform(offset))
+    }
+
+    /// Interpolates rotation, scale, skew and origin separately towards `to`.
+    pub fn interpolate_with(self, to: Self, weight: f32) -> Self {
+        Self::from_angle_scale_skew_origin(
+            math::lerp_angle(self.get_rotation(), to.get_rotation(), weight),
+            self.get_scale().lerp(to.get_scale(), weight),
+            math::lerp_angle(self.get_skew(), to.get_skew(), weight),
+            self.origin.lerp(to.origin, weight),
+        )
+    }
+
+    /// Transforms `v` by the basis only, ignoring `origin`.
+    pub fn basis_xform(self, v: Vector2) -> Vector2 {
+        self.x * v.x() + self.y * v.y()
+    }
+
+    /// Inverse-transforms `v` by the basis only, assuming it is orthonormal.
+    pub fn basis_xform_inv(self, v: Vector2) -> Vector2 {
+        Vector2::new(self.x.dot(v), self.y.dot(v))
+    }
+
+    /// Transforms the point `v`. Equivalent to `self * v`.
+    pub fn xform(self, v: Vector2) -> Vector2 {
+        self.basis_xform(v) + self.origin
+    }
+
+    /// Inverse-transforms the point `v`, assuming the basis is orthonormal.
+    ///
+    /// For transforms with scale or skew, use `self.affine_inverse().xform(v)` instead.
+    pub fn xform_inv(self, v: Vector2) -> Vector2 {
+        self.basis_xform_inv(v - self.origin)
+    }
+
+    /// Transforms `rect`, returning the axis-aligned bounding box of the result. Equivalent to `self * rect`.
+    pub fn xform_rect(self, rect: Rect2) -> Rect2 {
+        let x = self.x * rect.size.x();
+        let y = self.y * rect.size.y();
+        let position = self.xform(rect.position);
+
+        Rect2::new(position, Vector2::ZERO)
+            .expand(position + x)
+            .expand(position + y)
+            .expand(position + x + y)
+    }
+
+    /// Inverse-transforms `rect`, returning the axis-aligned bounding box of the result.
+    ///
+    /// Assumes the basis is orthonormal, like [`Transform2D::xform_inv()`].
+    pub fn xform_inv_rect(self, rect: Rect2) -> Rect2 {
+        let end = rect.end();
+        let corners = [
+            Vector2::new(rect.position.x(), end.y()),
+            end,
+            Vector2::new(end.x(), rect.position.y()),
+        ];
+
+        corners.into_iter().fold(
+            Rect2::new(self.xform_inv(rect.position), Vector2::ZERO),
+            |result, corner| result.expand(self.xform_inv(corner)),
+        )
+    }
+
+    /// Returns `true` if all components are approximately equal, see [`Vector2::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.x.is_equal_approx(other.x)
+            && self.y.is_equal_approx(other.y)
+            && self.origin.is_equal_approx(other.origin)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.origin.is_finite()
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Composes two transforms; `a * b` applies `b` first, then `a`.
+impl std::ops::Mul for Transform2D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.basis_xform(rhs.x),
+            self.basis_xform(rhs.y),
+            self.xform(rhs.origin),
+        )
+    }
+}
+
+impl std::ops::MulAssign for Transform2D {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::Mul<Vector2> for Transform2D {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        self.xform(rhs)
+    }
+}
+
+impl std::ops::Mul<Rect2> for Transform2D {
+    type Output = Rect2;
+
+    fn mul(self, rhs: Rect2) -> Rect2 {
+        self.xform_rect(rhs)
+    }
+}
+
+impl GodotFfi for Transform2D {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
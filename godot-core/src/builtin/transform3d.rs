@@ -0,0 +1,221 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{Basis, Plane, Vector3, AABB};
+
+/// 3D affine transform, consisting of a [`Basis`] and an `origin`.
+///
+/// Operations follow Godot semantics: methods without `_local` suffix (e.g. [`Transform3D::rotated()`])
+/// operate relative to the parent, i.e. they are applied _after_ `self`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Transform3D {
+    /// Rotation, scale and skew.
+    pub basis: Basis,
+
+    /// Translation offset.
+    pub origin: Vector3,
+}
+
+impl Transform3D {
+    /// Identity transform, without translation, rotation or scale.
+    pub const IDENTITY: Self = Self::new(Basis::IDENTITY, Vector3::ZERO);
+
+    pub const fn new(basis: Basis, origin: Vector3) -> Self {
+        Self { basis, origin }
+    }
+
+    /// Creates a transform from its three basis axes and `origin`.
+    pub fn from_cols(x: Vector3, y: Vector3, z: Vector3, origin: Vector3) -> Self {
+        Self::new(Basis::from_cols(x, y, z), origin)
+    }
+
+    /// Inverse of a transform with orthonormal basis (only rotation and translation). Faster than
+    /// [`Transform3D::affine_inverse()`].
+    pub fn inverse(self) -> Self {
+        let basis = self.basis.transposed();
+        Self::new(basis, basis * -self.origin)
+    }
+
+    /// Inverse of any invertible transform, including scale and skew.
+    ///
+    /// Does not panic for a singular basis (e.g. zero scale), but returns non-finite components, see
+    /// [`Basis::inverse()`].
+    pub fn affine_inverse(self) -> Self {
+        let basis = self.basis.inverse();
+        Self::new(basis, basis * -self.origin)
+    }
+
+    /// Returns the transform with orthonormalized basis, see [`Basis::orthonormalized()`].
+    pub fn orthonormalized(self) -> Self {
+        Self::new(self.basis.orthonormalized(), self.origin)
+    }
+
+    /// Rotates the transform so that its forward axis (-Z) points towards `target`, keeping `origin`.
+    ///
+    /// Degenerate inputs result in NaN components, see [`Basis::looking_at()`].
+    pub fn looking_at(self, target: Vector3, up: Vector3) -> Self {
+        Self::new(Basis::looking_at(target - self.origin, up), self.origin)
+    }
+
+    /// Rotates the transform by `angle` radians around the normalized `axis`, relative to the parent.
+    pub fn rotated(self, axis: Vector3, angle: f32) -> Self {
+        Self::new(Basis::from_axis_angle(axis, angle), Vector3::ZERO) * self
+    }
+
+    /// Rotates the transform by `angle` radians around the normalized `axis`, in its own local space.
+    pub fn rotated_local(self, axis: Vector3, angle: f32) -> Self {
+        self * Self::new(Basis::from_axis_angle(axis, angle), Vector3::ZERO)
+    }
+
+    /// Scales the transform (including `origin`) relative to the parent.
+    pub fn scaled(self, scale: Vector3) -> Self {
+        Self::new(self.basis.scaled(scale), self.origin * scale)
+    }
+
+    /// Scales the transform's axes in its own local space, keeping `origin`.
+    pub fn scaled_local(self, scale: Vector3) -> Self {
+        Self::new(self.basis * Basis::from_scale(scale), self.origin)
+    }
+
+    /// Translates the transform by `offset`, relative to the parent.
+    pub fn translated(self, offset: Vector3) -> Self {
+        Self::new(self.basis, self.origin + offset)
+    }
+
+    /// Translates the transform by `offset`, in its own local space.
+    pub fn translated_local(self, offset: Vector3) -> Self {
+        Self::new(self.basis, self.origin + self.basis * offset)
+    }
+
+    /// Interpolates rotation (spherically), scale and origin separately towards `to`.
+    pub fn interpolate_with(self, to: Self, weight: f32) -> Self {
+        let rotation = self
+            .basis
+            .get_rotation_quaternion()
+            .slerp(to.basis.get_rotation_quaternion(), weight)
+            .normalized();
+        let scale = self.basis.get_scale().lerp(to.basis.get_scale(), weight);
+
+        Self::new(
+            Basis::from_quaternion(rotation) * Basis::from_scale(scale),
+            self.origin.lerp(to.origin, weight),
+        )
+    }
+
+    /// Transforms the point `v`. Equivalent to `self * v`.
+    pub fn xform(self, v: Vector3) -> Vector3 {
+        self.basis * v + self.origin
+    }
+
+    /// Inverse-transforms the point `v`, assuming the basis is orthonormal.
+    ///
+    /// For transforms with scale or skew, use `self.affine_inverse().xform(v)` instead.
+    pub fn xform_inv(self, v: Vector3) -> Vector3 {
+        self.basis.transposed() * (v - self.origin)
+    }
+
+    /// Transforms `aabb`, returning the axis-aligned bounding box of the result. Equivalent to `self * aabb`.
+    pub fn xform_aabb(self, aabb: AABB) -> AABB {
+        let min = to_array(aabb.position);
+        let max = to_array(aabb.end());
+        let mut tmin = to_array(self.origin);
+        let mut tmax = tmin;
+
+        for i in 0..3 {
+            let row = to_array(self.basis.rows[i]);
+            for j in 0..3 {
+                let e = row[j] * min[j];
+                let f = row[j] * max[j];
+                tmin[i] += e.min(f);
+                tmax[i] += e.max(f);
+            }
+        }
+
+        let position = Vector3::new(tmin[0], tmin[1], tmin[2]);
+        let end = Vector3::new(tmax[0], tmax[1], tmax[2]);
+        AABB::new(position, end - position)
+    }
+
+    /// Inverse-transforms `aabb`, returning the axis-aligned bounding box of the result.
+    ///
+    /// Assumes the basis is orthonormal, like [`Transform3D::xform_inv()`].
+    pub fn xform_inv_aabb(self, aabb: AABB) -> AABB {
+        (1..8).fold(
+            AABB::new(self.xform_inv(aabb.get_endpoint(0)), Vector3::ZERO),
+            |result, i| result.expand(self.xform_inv(aabb.get_endpoint(i))),
+        )
+    }
+
+    /// Transforms `plane`. Equivalent to `self * plane`.
+    ///
+    /// Normals are transformed by the inverse transpose of the basis, so this also works with non-uniform scale.
+    pub fn xform_plane(self, plane: Plane) -> Plane {
+        let point = self.xform(plane.normal * plane.d);
+        let normal = (self.basis.inverse().transposed() * plane.normal).normalized();
+
+        Plane::from_normal_point(normal, point)
+    }
+
+    /// Returns `true` if basis and origin are approximately equal.
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.basis.is_equal_approx(other.basis) && self.origin.is_equal_approx(other.origin)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.basis.is_finite() && self.origin.is_finite()
+    }
+}
+
+/// Composes two transforms; `a * b` applies `b` first, then `a`.
+impl std::ops::Mul for Transform3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.basis * rhs.basis, self.xform(rhs.origin))
+    }
+}
+
+impl std::ops::MulAssign for Transform3D {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::Mul<Vector3> for Transform3D {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        self.xform(rhs)
+    }
+}
+
+impl std::ops::Mul<AABB> for Transform3D {
+    type Output = AABB;
+
+    fn mul(self, rhs: AABB) -> AABB {
+        self.xform_aabb(rhs)
+    }
+}
+
+impl std::ops::Mul<Plane> for Transform3D {
+    type Output = Plane;
+
+    fn mul(self, rhs: Plane) -> Plane {
+        self.xform_plane(rhs)
+    }
+}
+
+impl GodotFfi for Transform3D {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
+
+fn to_array(v: Vector3) -> [f32; 3] {
+    [v.x(), v.y(), v.z()]
+}
@@ -0,0 +1,293 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Dynamic method calls, member access and iteration on `Variant`, as available in GDScript.
+//!
+//! These work for any variant type, including objects, and are resolved by Godot at runtime.
+
+use super::*;
+use crate::builtin::StringName;
+use std::error::Error;
+
+impl Variant {
+    /// Calls `method` with the given arguments, like `variant.method(args...)` in GDScript.
+    ///
+    /// Works for objects as well as builtin types, including vararg methods, which are not available as ptrcalls.
+    /// Takes `&mut self`, because methods of value types (e.g. `PackedInt32Array.push_back()`) modify the variant
+    /// in place.
+    pub fn call<S>(&mut self, method: S, args: &[Variant]) -> Result<Variant, CallError>
+    where
+        S: Into<StringName>,
+    {
+        let method = method.into();
+        let args_sys = args.iter().map(Variant::var_sys).collect::<Vec<_>>();
+        let mut error = sys::default_call_error();
+
+        let result = unsafe {
+            Variant::from_var_sys_init(|return_ptr| {
+                interface_fn!(variant_call)(
+                    self.var_sys_mut(),
+                    method.string_sys(),
+                    args_sys.as_ptr(),
+                    args_sys.len() as i64,
+                    return_ptr,
+                    &mut error,
+                );
+            })
+        };
+
+        match CallError::from_sys(&error) {
+            None => Ok(result),
+            Some(error) => Err(error),
+        }
+    }
+
+    /// Like [`call()`][Self::call], for methods which are known to exist.
+    ///
+    /// # Panics
+    /// If Godot reports an error for the call.
+    pub(crate) fn call_panicking(&mut self, method: &str, args: &[Variant]) -> Variant {
+        self.call(method, args)
+            .unwrap_or_else(|e| panic!("{method}() failed: {e}"))
+    }
+
+    /// Returns `true` if the value has a method named `method`.
+    pub fn has_method<S>(&self, method: S) -> bool
+    where
+        S: Into<StringName>,
+    {
+        let method = method.into();
+        unsafe { interface_fn!(variant_has_method)(self.var_sys(), method.string_sys()) != 0 }
+    }
+
+    /// Returns the member or element at `key`, like `variant[key]` in GDScript.
+    ///
+    /// String keys also access named members, e.g. `x` of a `Vector2` or properties of an object. Returns `None` if
+    /// there is no such member or key.
+    pub fn get<K: ToVariant>(&self, key: K) -> Option<Variant> {
+        let key = key.to_variant();
+        let mut is_valid: sys::GDNativeBool = 0;
+
+        // Like in evaluate(), the result must be initialized
+        let mut result = Variant::nil();
+        unsafe {
+            interface_fn!(variant_get)(
+                self.var_sys(),
+                key.var_sys(),
+                result.var_sys_mut(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        (is_valid != 0).then_some(result)
+    }
+
+    /// Sets the member or element at `key` to `value`, like `variant[key] = value` in GDScript.
+    ///
+    /// Returns `false` if there is no such member, or if it cannot store `value`.
+    pub fn set<K: ToVariant, V: ToVariant>(&mut self, key: K, value: V) -> bool {
+        let key = key.to_variant();
+        let value = value.to_variant();
+        let mut is_valid: sys::GDNativeBool = 0;
+
+        unsafe {
+            interface_fn!(variant_set)(
+                self.var_sys_mut(),
+                key.var_sys(),
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        is_valid != 0
+    }
+
+    /// Returns the element at `index` of an indexable type, such as arrays, strings and vectors.
+    ///
+    /// Returns `None` if the type is not indexable, or if `index` is out of bounds.
+    pub fn get_indexed(&self, index: usize) -> Option<Variant> {
+        let mut is_valid: sys::GDNativeBool = 0;
+        let mut is_out_of_bounds: sys::GDNativeBool = 0;
+
+        let mut result = Variant::nil();
+        unsafe {
+            interface_fn!(variant_get_indexed)(
+                self.var_sys(),
+                index as i64,
+                result.var_sys_mut(),
+                ptr::addr_of_mut!(is_valid),
+                ptr::addr_of_mut!(is_out_of_bounds),
+            )
+        };
+
+        (is_valid != 0 && is_out_of_bounds == 0).then_some(result)
+    }
+
+    /// Sets the element at `index` of an indexable type to `value`.
+    ///
+    /// Returns `false` if the type is not indexable, if `index` is out of bounds, or if the element cannot store `value`.
+    pub fn set_indexed<V: ToVariant>(&mut self, index: usize, value: V) -> bool {
+        let value = value.to_variant();
+        let mut is_valid: sys::GDNativeBool = 0;
+        let mut is_out_of_bounds: sys::GDNativeBool = 0;
+
+        unsafe {
+            interface_fn!(variant_set_indexed)(
+                self.var_sys_mut(),
+                index as i64,
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+                ptr::addr_of_mut!(is_out_of_bounds),
+            )
+        };
+
+        is_valid != 0 && is_out_of_bounds == 0
+    }
+
+    /// Iterates over the value like a GDScript `for` loop.
+    ///
+    /// Yields the elements of arrays, the keys of dictionaries, the characters of strings and `0..n` for an integer
+    /// `n`. Objects can implement GDScript's iterator protocol (`_iter_init()` etc.). The iterator is empty for
+    /// types which are not iterable.
+    pub fn iter(&self) -> VariantIter {
+        VariantIter::new(self.clone())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over the elements of a variant, see [`Variant::iter()`].
+pub struct VariantIter {
+    variant: Variant,
+
+    /// Godot's iteration state, or `None` once the iterator is exhausted.
+    state: Option<Variant>,
+}
+
+impl VariantIter {
+    pub(crate) fn new(variant: Variant) -> Self {
+        let mut state = Variant::nil();
+        let mut is_valid: sys::GDNativeBool = 0;
+
+        let has_next = unsafe {
+            interface_fn!(variant_iter_init)(
+                variant.var_sys(),
+                state.var_sys_mut(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        Self {
+            variant,
+            state: (is_valid != 0 && has_next != 0).then_some(state),
+        }
+    }
+}
+
+impl Iterator for VariantIter {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = self.state.take()?;
+        let mut element = Variant::nil();
+        let mut is_valid: sys::GDNativeBool = 0;
+
+        let has_next = unsafe {
+            interface_fn!(variant_iter_get)(
+                self.variant.var_sys(),
+                state.var_sys_mut(),
+                element.var_sys_mut(),
+                ptr::addr_of_mut!(is_valid),
+            );
+
+            interface_fn!(variant_iter_next)(
+                self.variant.var_sys(),
+                state.var_sys_mut(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        if has_next != 0 {
+            self.state = Some(state);
+        }
+
+        Some(element)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when calling a method through [`Variant::call()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CallError {
+    /// The value has no method of this name.
+    InvalidMethod,
+
+    /// The argument at `index` cannot be converted to the parameter type `expected`.
+    InvalidArgument { index: usize, expected: VariantType },
+
+    /// More arguments were passed than the method accepts.
+    TooManyArguments { expected: usize },
+
+    /// Fewer arguments were passed than the method requires.
+    TooFewArguments { expected: usize },
+
+    /// The method was called on a null object.
+    InstanceIsNull,
+
+    /// A non-const method was called on a read-only value.
+    MethodNotConst,
+
+    /// Godot reported an error code which is not known to this version of the bindings.
+    Unknown { code: i32 },
+}
+
+impl CallError {
+    /// Returns `None` if `error` signals success.
+    pub(crate) fn from_sys(error: &sys::GDNativeCallError) -> Option<Self> {
+        let result = match error.error {
+            sys::GDNATIVE_CALL_OK => return None,
+            sys::GDNATIVE_CALL_ERROR_INVALID_METHOD => Self::InvalidMethod,
+            sys::GDNATIVE_CALL_ERROR_INVALID_ARGUMENT => Self::InvalidArgument {
+                index: error.argument as usize,
+                expected: VariantType::from_ord(error.expected as _),
+            },
+            sys::GDNATIVE_CALL_ERROR_TOO_MANY_ARGUMENTS => Self::TooManyArguments {
+                expected: error.expected as usize,
+            },
+            sys::GDNATIVE_CALL_ERROR_TOO_FEW_ARGUMENTS => Self::TooFewArguments {
+                expected: error.expected as usize,
+            },
+            sys::GDNATIVE_CALL_ERROR_INSTANCE_IS_NULL => Self::InstanceIsNull,
+            sys::GDNATIVE_CALL_ERROR_METHOD_NOT_CONST => Self::MethodNotConst,
+            code => Self::Unknown { code: code as i32 },
+        };
+
+        Some(result)
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMethod => write!(f, "invalid method"),
+            Self::InvalidArgument { index, expected } => {
+                write!(f, "argument [{index}] cannot be converted to {expected:?}")
+            }
+            Self::TooManyArguments { expected } => {
+                write!(f, "too many arguments, expected {expected}")
+            }
+            Self::TooFewArguments { expected } => {
+                write!(f, "too few arguments, expected {expected}")
+            }
+            Self::InstanceIsNull => write!(f, "instance is null"),
+            Self::MethodNotConst => write!(f, "method is not const"),
+            Self::Unknown { code } => write!(f, "unknown call error {code}"),
+        }
+    }
+}
+
+impl Error for CallError {}
@@ -7,11 +7,27 @@
 use super::*;
 use crate::builtin::*;
 use godot_ffi as sys;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
 use sys::GodotFfi;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Macro definitions
 
+macro_rules! impl_ptrcall_marshal_sys {
+    ($T:ty) => {
+        impl PtrcallMarshal for $T {
+            unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+                try_from_ptr_sys(ptr)
+            }
+
+            unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+                try_write_ptr_sys(self, dst)
+            }
+        }
+    };
+}
+
 macro_rules! impl_variant_traits {
     ($T:ty, $from_fn:ident, $to_fn:ident, $variant_type:ident) => {
         impl_variant_traits!(@@ $T, $from_fn, $to_fn, $variant_type;);
@@ -41,6 +57,13 @@ macro_rules! impl_variant_traits {
 
         impl FromVariant for $T {
             fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+                // The converter does not check the type: it reinterprets (or for some types, converts) the value
+                let expected = VariantType::from_ord(sys::$variant_type);
+                if variant.get_type() != expected {
+                    let reason = VariantConversionReason::BadType;
+                    return Err(VariantConversionError::new(expected, variant, reason));
+                }
+
                 // In contrast to T -> Variant, the conversion Variant -> T assumes
                 // that the destination is initialized (at least for some T). For example:
                 // void String::operator=(const String &p_str) { _cowdata._ref(p_str._cowdata); }
@@ -65,6 +88,8 @@ macro_rules! impl_variant_traits {
 
             $($extra)*
         }
+
+        impl_ptrcall_marshal_sys!($T);
     };
 }
 
@@ -78,8 +103,15 @@ macro_rules! impl_variant_traits_int {
 
         impl FromVariant for $T {
             fn try_from_variant(v: &Variant) -> Result<Self, VariantConversionError> {
-                i64::try_from_variant(v)
-                    .and_then(|i| <$T>::try_from(i).map_err(|_e| VariantConversionError))
+                i64::try_from_variant(v).and_then(|i| {
+                    <$T>::try_from(i).map_err(|_e| {
+                        VariantConversionError::new(
+                            VariantType::Int,
+                            v,
+                            VariantConversionReason::OutOfRange,
+                        )
+                    })
+                })
             }
         }
 
@@ -92,6 +124,8 @@ macro_rules! impl_variant_traits_int {
                 sys::$param_metadata
             }
         }
+
+        impl_ptrcall_marshal_sys!($T);
     };
 }
 
@@ -119,6 +153,8 @@ macro_rules! impl_variant_traits_float {
                 sys::$param_metadata
             }
         }
+
+        impl_ptrcall_marshal_sys!($T);
     };
 }
 
@@ -135,9 +171,34 @@ mod impls {
     impl_variant_traits!(Vector4, vector4_to_variant, vector4_from_variant, GDNATIVE_VARIANT_TYPE_VECTOR4);
     impl_variant_traits!(Vector2i, vector2i_to_variant, vector2i_from_variant, GDNATIVE_VARIANT_TYPE_VECTOR2I);
     impl_variant_traits!(Vector3i, vector3i_to_variant, vector3i_from_variant, GDNATIVE_VARIANT_TYPE_VECTOR3I);
+    impl_variant_traits!(Vector4i, vector4i_to_variant, vector4i_from_variant, GDNATIVE_VARIANT_TYPE_VECTOR4I);
+    impl_variant_traits!(Rect2, rect2_to_variant, rect2_from_variant, GDNATIVE_VARIANT_TYPE_RECT2);
+    impl_variant_traits!(Rect2i, rect2i_to_variant, rect2i_from_variant, GDNATIVE_VARIANT_TYPE_RECT2I);
+    impl_variant_traits!(Transform2D, transform2d_to_variant, transform2d_from_variant, GDNATIVE_VARIANT_TYPE_TRANSFORM2D);
+    impl_variant_traits!(Plane, plane_to_variant, plane_from_variant, GDNATIVE_VARIANT_TYPE_PLANE);
+    impl_variant_traits!(Quaternion, quaternion_to_variant, quaternion_from_variant, GDNATIVE_VARIANT_TYPE_QUATERNION);
+    impl_variant_traits!(AABB, aabb_to_variant, aabb_from_variant, GDNATIVE_VARIANT_TYPE_AABB);
+    impl_variant_traits!(Basis, basis_to_variant, basis_from_variant, GDNATIVE_VARIANT_TYPE_BASIS);
+    impl_variant_traits!(Transform3D, transform3d_to_variant, transform3d_from_variant, GDNATIVE_VARIANT_TYPE_TRANSFORM3D);
+    impl_variant_traits!(Projection, projection_to_variant, projection_from_variant, GDNATIVE_VARIANT_TYPE_PROJECTION);
     impl_variant_traits!(Color, color_to_variant, color_from_variant, GDNATIVE_VARIANT_TYPE_COLOR);
     impl_variant_traits!(GodotString, string_to_variant, string_from_variant, GDNATIVE_VARIANT_TYPE_STRING);
     impl_variant_traits!(StringName, string_name_to_variant, string_name_from_variant, GDNATIVE_VARIANT_TYPE_STRING_NAME);
+    impl_variant_traits!(NodePath, node_path_to_variant, node_path_from_variant, GDNATIVE_VARIANT_TYPE_NODE_PATH);
+    impl_variant_traits!(Callable, callable_to_variant, callable_from_variant, GDNATIVE_VARIANT_TYPE_CALLABLE);
+    impl_variant_traits!(Signal, signal_to_variant, signal_from_variant, GDNATIVE_VARIANT_TYPE_SIGNAL);
+    impl_variant_traits!(Rid, rid_to_variant, rid_from_variant, GDNATIVE_VARIANT_TYPE_RID);
+    impl_variant_traits!(Dictionary, dictionary_to_variant, dictionary_from_variant, GDNATIVE_VARIANT_TYPE_DICTIONARY);
+    impl_variant_traits!(Array, array_to_variant, array_from_variant, GDNATIVE_VARIANT_TYPE_ARRAY);
+    impl_variant_traits!(ByteArray, packed_byte_array_to_variant, packed_byte_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_BYTE_ARRAY);
+    impl_variant_traits!(Int32Array, packed_int32_array_to_variant, packed_int32_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_INT32_ARRAY);
+    impl_variant_traits!(Int64Array, packed_int64_array_to_variant, packed_int64_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_INT64_ARRAY);
+    impl_variant_traits!(Float32Array, packed_float32_array_to_variant, packed_float32_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_FLOAT32_ARRAY);
+    impl_variant_traits!(Float64Array, packed_float64_array_to_variant, packed_float64_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_FLOAT64_ARRAY);
+    impl_variant_traits!(StringArray, packed_string_array_to_variant, packed_string_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_STRING_ARRAY);
+    impl_variant_traits!(Vector2Array, packed_vector2_array_to_variant, packed_vector2_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_VECTOR2_ARRAY);
+    impl_variant_traits!(Vector3Array, packed_vector3_array_to_variant, packed_vector3_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_VECTOR3_ARRAY);
+    impl_variant_traits!(ColorArray, packed_color_array_to_variant, packed_color_array_from_variant, GDNATIVE_VARIANT_TYPE_PACKED_COLOR_ARRAY);
 
 
     impl_variant_traits!(i64, int_to_variant, int_from_variant, GDNATIVE_VARIANT_TYPE_INT,
@@ -172,6 +233,8 @@ impl VariantMetadata for () {
     }
 }
 
+impl_ptrcall_marshal_sys!(());
+
 impl ToVariant for Variant {
     fn to_variant(&self) -> Variant {
         self.clone()
@@ -190,3 +253,364 @@ impl VariantMetadata for Variant {
         sys::GDNATIVE_VARIANT_TYPE_NIL
     }
 }
+
+impl_ptrcall_marshal_sys!(Variant);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust standard types
+
+macro_rules! impl_variant_metadata_delegate {
+    ($T:ident) => {
+        fn variant_type() -> sys::GDNativeVariantType {
+            $T::variant_type()
+        }
+
+        fn class_name() -> &'static str {
+            $T::class_name()
+        }
+
+        fn property_info(property_name: &str) -> sys::GDNativePropertyInfo {
+            $T::property_info(property_name)
+        }
+
+        fn param_metadata() -> sys::GDNativeExtensionClassMethodArgumentMetadata {
+            $T::param_metadata()
+        }
+    };
+}
+
+macro_rules! impl_variant_traits_tuple {
+    ($len:literal; $($Tn:ident : $n:tt),+) => {
+        impl<$($Tn: ToVariant),+> ToVariant for ($($Tn,)+) {
+            fn to_variant(&self) -> Variant {
+                let mut array = Array::new();
+                $(
+                    array.push(self.$n.to_variant());
+                )+
+                array.to_variant()
+            }
+        }
+
+        impl<$($Tn: FromVariant),+> FromVariant for ($($Tn,)+) {
+            fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+                let array = Array::try_from_variant(variant)?;
+                if array.len() != $len {
+                    return Err(wrong_length(variant));
+                }
+
+                Ok(($(
+                    $Tn::try_from_variant(&array.get($n).unwrap())?,
+                )+))
+            }
+        }
+
+        impl<$($Tn),+> VariantMetadata for ($($Tn,)+) {
+            fn variant_type() -> sys::GDNativeVariantType {
+                sys::GDNATIVE_VARIANT_TYPE_ARRAY
+            }
+        }
+
+        impl<$($Tn: ToVariant + FromVariant),+> PtrcallMarshal for ($($Tn,)+) {
+            unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+                try_from_ptr_via::<Array, Self>(ptr)
+            }
+
+            unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+                try_write_ptr_via::<Array, Self>(self, dst)
+            }
+        }
+    };
+}
+
+/// Error for arrays which do not have the length of a Rust tuple or fixed-size array.
+fn wrong_length(variant: &Variant) -> VariantConversionError {
+    VariantConversionError::new(
+        VariantType::Array,
+        variant,
+        VariantConversionReason::OutOfRange,
+    )
+}
+
+// Option: None is stored as nil
+impl<T: ToVariant> ToVariant for Option<T> {
+    fn to_variant(&self) -> Variant {
+        match self {
+            Some(value) => value.to_variant(),
+            None => Variant::nil(),
+        }
+    }
+}
+
+impl<T: FromVariant> FromVariant for Option<T> {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        if variant.is_nil() {
+            return Ok(None);
+        }
+
+        match T::try_from_variant(variant) {
+            Ok(value) => Ok(Some(value)),
+            // A null object has type Object, but is equivalent to nil in GDScript
+            Err(e) if e.reason() == VariantConversionReason::NullObject => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+// Option<Gd<T>> is passed as a (possibly null) object. Other values are passed as Variant, so that None can be nil
+impl<T: VariantMetadata> VariantMetadata for Option<T> {
+    fn variant_type() -> sys::GDNativeVariantType {
+        if is_object::<T>() {
+            T::variant_type()
+        } else {
+            Variant::variant_type()
+        }
+    }
+
+    fn class_name() -> &'static str {
+        T::class_name()
+    }
+
+    fn property_info(property_name: &str) -> sys::GDNativePropertyInfo {
+        if is_object::<T>() {
+            T::property_info(property_name)
+        } else {
+            Variant::property_info(property_name)
+        }
+    }
+
+    fn param_metadata() -> sys::GDNativeExtensionClassMethodArgumentMetadata {
+        if is_object::<T>() {
+            T::param_metadata()
+        } else {
+            Variant::param_metadata()
+        }
+    }
+}
+
+impl<T> PtrcallMarshal for Option<T>
+where
+    T: PtrcallMarshal + ToVariant + FromVariant + VariantMetadata,
+{
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        if !is_object::<T>() {
+            // The variant is owned by Godot, so only borrow it -- an owned temporary would destroy Godot's argument
+            let variant = &*(ptr as *const Variant);
+            return Self::try_from_variant(variant).map_err(|e| e.to_string());
+        }
+
+        // Objects are passed as Object**, pointing to null for null objects
+        if (*(ptr as *const sys::GDNativeObjectPtr)).is_null() {
+            Ok(None)
+        } else {
+            T::try_from_ptr(ptr).map(Some)
+        }
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        if !is_object::<T>() {
+            return try_write_ptr_via::<Variant, Self>(self, dst);
+        }
+
+        match self {
+            Some(value) => value.try_write_ptr(dst),
+            None => {
+                *(dst as *mut sys::GDNativeObjectPtr) = std::ptr::null_mut();
+                Ok(())
+            }
+        }
+    }
+}
+
+fn is_object<T: VariantMetadata>() -> bool {
+    T::variant_type() == sys::GDNATIVE_VARIANT_TYPE_OBJECT
+}
+
+// Box: same representation as the boxed value
+impl<T: ToVariant + ?Sized> ToVariant for Box<T> {
+    fn to_variant(&self) -> Variant {
+        (**self).to_variant()
+    }
+}
+
+impl<T: FromVariant> FromVariant for Box<T> {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        T::try_from_variant(variant).map(Box::new)
+    }
+}
+
+impl<T: VariantMetadata> VariantMetadata for Box<T> {
+    impl_variant_metadata_delegate!(T);
+}
+
+impl<T: PtrcallMarshal> PtrcallMarshal for Box<T> {
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        T::try_from_ptr(ptr).map(Box::new)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        (*self).try_write_ptr(dst)
+    }
+}
+
+// Slices and Vec: stored as untyped Array
+impl<T: ToVariant> ToVariant for [T] {
+    fn to_variant(&self) -> Variant {
+        self.iter()
+            .map(T::to_variant)
+            .collect::<Array>()
+            .to_variant()
+    }
+}
+
+impl<T: ToVariant> ToVariant for &[T] {
+    fn to_variant(&self) -> Variant {
+        (**self).to_variant()
+    }
+}
+
+impl<T: ToVariant> ToVariant for Vec<T> {
+    fn to_variant(&self) -> Variant {
+        self.as_slice().to_variant()
+    }
+}
+
+impl<T: FromVariant> FromVariant for Vec<T> {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        let array = Array::try_from_variant(variant)?;
+        array
+            .iter()
+            .map(|element| T::try_from_variant(&element))
+            .collect()
+    }
+}
+
+impl<T> VariantMetadata for Vec<T> {
+    fn variant_type() -> sys::GDNativeVariantType {
+        sys::GDNATIVE_VARIANT_TYPE_ARRAY
+    }
+}
+
+impl<T: ToVariant + FromVariant> PtrcallMarshal for Vec<T> {
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_via::<Array, Self>(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_via::<Array, Self>(self, dst)
+    }
+}
+
+// Fixed-size arrays: stored as Array of the same length
+impl<T: ToVariant, const N: usize> ToVariant for [T; N] {
+    fn to_variant(&self) -> Variant {
+        self.as_slice().to_variant()
+    }
+}
+
+impl<T: FromVariant, const N: usize> FromVariant for [T; N] {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        let vec = Vec::<T>::try_from_variant(variant)?;
+        Self::try_from(vec).map_err(|_| wrong_length(variant))
+    }
+}
+
+impl<T, const N: usize> VariantMetadata for [T; N] {
+    fn variant_type() -> sys::GDNativeVariantType {
+        sys::GDNATIVE_VARIANT_TYPE_ARRAY
+    }
+}
+
+impl<T: ToVariant + FromVariant, const N: usize> PtrcallMarshal for [T; N] {
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_via::<Array, Self>(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_via::<Array, Self>(self, dst)
+    }
+}
+
+// Maps: stored as Dictionary
+impl<K: ToVariant, V: ToVariant, S> ToVariant for HashMap<K, V, S> {
+    fn to_variant(&self) -> Variant {
+        Dictionary::from(self).to_variant()
+    }
+}
+
+impl<K, V, S> FromVariant for HashMap<K, V, S>
+where
+    K: FromVariant + Eq + Hash,
+    V: FromVariant,
+    S: BuildHasher + Default,
+{
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        let dictionary = Dictionary::try_from_variant(variant)?;
+        Self::try_from(&dictionary)
+    }
+}
+
+impl<K, V, S> VariantMetadata for HashMap<K, V, S> {
+    fn variant_type() -> sys::GDNativeVariantType {
+        sys::GDNATIVE_VARIANT_TYPE_DICTIONARY
+    }
+}
+
+impl<K, V, S> PtrcallMarshal for HashMap<K, V, S>
+where
+    K: ToVariant + FromVariant + Eq + Hash,
+    V: ToVariant + FromVariant,
+    S: BuildHasher + Default,
+{
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_via::<Dictionary, Self>(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_via::<Dictionary, Self>(self, dst)
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> ToVariant for BTreeMap<K, V> {
+    fn to_variant(&self) -> Variant {
+        Dictionary::from(self).to_variant()
+    }
+}
+
+impl<K: FromVariant + Ord, V: FromVariant> FromVariant for BTreeMap<K, V> {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        let dictionary = Dictionary::try_from_variant(variant)?;
+        Self::try_from(&dictionary)
+    }
+}
+
+impl<K, V> VariantMetadata for BTreeMap<K, V> {
+    fn variant_type() -> sys::GDNativeVariantType {
+        sys::GDNATIVE_VARIANT_TYPE_DICTIONARY
+    }
+}
+
+impl<K, V> PtrcallMarshal for BTreeMap<K, V>
+where
+    K: ToVariant + FromVariant + Ord,
+    V: ToVariant + FromVariant,
+{
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_via::<Dictionary, Self>(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_via::<Dictionary, Self>(self, dst)
+    }
+}
+
+// Tuples: stored as Array of the same length
+impl_variant_traits_tuple!(1; T0: 0);
+impl_variant_traits_tuple!(2; T0: 0, T1: 1);
+impl_variant_traits_tuple!(3; T0: 0, T1: 1, T2: 2);
+impl_variant_traits_tuple!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_variant_traits_tuple!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_variant_traits_tuple!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_variant_traits_tuple!(7; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_variant_traits_tuple!(8; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+impl_variant_traits_tuple!(9; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8);
+impl_variant_traits_tuple!(10; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9);
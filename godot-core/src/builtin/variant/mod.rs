@@ -7,22 +7,26 @@
 use crate::builtin::GodotString;
 use godot_ffi as sys;
 use godot_ffi::GodotFfi;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::{fmt, ptr};
 use sys::types::OpaqueVariant;
 use sys::{ffi_methods, interface_fn};
 
+mod dynamic;
 mod impls;
+mod operators;
 mod variant_metadata;
 mod variant_traits;
 
+pub use dynamic::*;
 pub use impls::*;
 pub use variant_metadata::*;
 pub use variant_traits::*;
 
+pub use crate::engine::gen::central_core::VariantDispatch;
 pub use sys::{VariantOperator, VariantType};
 
-// pub use crate::gen::central::*;
-
 #[repr(C, align(8))]
 pub struct Variant {
     opaque: OpaqueVariant,
@@ -58,44 +62,113 @@ impl Variant {
 
     /// Convert to type `T`, returning `Err` on failure.
     ///
-    /// Equivalent to `T::try_from_variant(&self)`.
+    /// Equivalent to `T::try_from_variant(&self)`. Fails if the variant holds a different type than `T`, even if
+    /// Godot could convert it; see [`try_to_coerced()`][Self::try_to_coerced] for numeric conversions.
     pub fn try_to<T: FromVariant>(&self) -> Result<T, VariantConversionError> {
         T::try_from_variant(self)
     }
 
+    /// Convert to type `T` with Godot's lossy conversions between `bool`, `int` and `float`, returning `Err` on failure.
+    ///
+    /// Where [`try_to()`][Self::try_to] requires the variant to hold exactly the target type, this behaves like
+    /// GDScript's `int(value)`, `float(value)` and `bool(value)`: for example, `2.7` converts to the integer 2, and any
+    /// non-zero number converts to `true`. All other conversions are as strict as in `try_to()`.
+    pub fn try_to_coerced<T>(&self) -> Result<T, VariantConversionError>
+    where
+        T: FromVariant + VariantMetadata,
+    {
+        let source = self.get_type();
+        let target = VariantType::from_ord(T::variant_type());
+        let is_scalar = |ty| {
+            matches!(
+                ty,
+                VariantType::Bool | VariantType::Int | VariantType::Float
+            )
+        };
+
+        if source == target || !is_scalar(source) || !is_scalar(target) {
+            return T::try_from_variant(self);
+        }
+
+        let args = [self.var_sys()];
+        let mut error = sys::default_call_error();
+        let coerced = unsafe {
+            Variant::from_var_sys_init(|variant_ptr| {
+                interface_fn!(variant_construct)(
+                    target.to_ord(),
+                    variant_ptr,
+                    args.as_ptr(),
+                    args.len() as i32,
+                    &mut error,
+                );
+            })
+        };
+
+        // bool, int and float are all constructible from each other
+        debug_assert_eq!(error.error, sys::GDNATIVE_CALL_OK);
+        T::try_from_variant(&coerced)
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     pub fn is_nil(&self) -> bool {
         self.sys_type() == sys::GDNATIVE_VARIANT_TYPE_NIL
     }
 
-    // TODO test
     pub fn get_type(&self) -> VariantType {
         let ty_sys = unsafe { interface_fn!(variant_get_type)(self.var_sys()) };
         VariantType::from_ord(ty_sys)
     }
 
-    // TODO test
-    #[allow(unused_mut)]
+    /// Converts the value to the Rust type corresponding to its [`VariantType`], for exhaustive `match`es.
+    ///
+    /// ```ignore
+    /// match variant.dispatch() {
+    ///     VariantDispatch::Int(int) => godot_print!("int {int}"),
+    ///     VariantDispatch::String(string) => godot_print!("string {string}"),
+    ///     _ => {}
+    /// }
+    /// ```
+    pub fn dispatch(&self) -> VariantDispatch {
+        VariantDispatch::try_from_variant(self)
+            .expect("conversion to VariantDispatch is infallible")
+    }
+
+    /// Applies the operator `op` like GDScript would, or returns `None` if it is not defined for the operand types.
+    ///
+    /// For unary operators, `rhs` is ignored. Arithmetic operators are also available through `std::ops` traits,
+    /// with `checked_*` variants such as [`Variant::checked_add()`].
     pub fn evaluate(&self, rhs: &Variant, op: VariantOperator) -> Option<Variant> {
         let op_sys = op.to_ord();
-        let mut is_valid = false as u8;
+        let mut is_valid: sys::GDNativeBool = 0;
 
+        // Godot assigns to an existing Variant, so the result must be initialized
         let mut result = Variant::nil();
         unsafe {
             interface_fn!(variant_evaluate)(
                 op_sys,
                 self.var_sys(),
                 rhs.var_sys(),
-                result.var_sys(),
+                result.var_sys_mut(),
                 ptr::addr_of_mut!(is_valid),
             )
         };
 
-        if is_valid == 1 {
-            Some(result)
-        } else {
-            None
-        }
+        (is_valid != 0).then_some(result)
+    }
+
+    /// Returns the truthiness of the value, as used by GDScript's `if` and `not`.
+    ///
+    /// Nil, `false`, zero, and empty strings and containers are falsy; everything else is truthy.
+    pub fn booleanize(&self) -> bool {
+        unsafe { interface_fn!(variant_booleanize)(self.var_sys()) != 0 }
+    }
+
+    /// Returns `true` if both values have the same type and content, comparing containers element by element.
+    ///
+    /// This is the equality used by [`PartialEq`], and is consistent with [`Hash`]. In contrast to GDScript's `==`
+    /// (see [`Variant::evaluate()`]), values of different types, like `1` and `1.0`, are never equal.
+    pub fn hash_compare(&self, other: &Variant) -> bool {
+        unsafe { interface_fn!(variant_hash_compare)(self.var_sys(), other.var_sys()) != 0 }
     }
 
     pub(crate) fn sys_type(&self) -> sys::GDNativeVariantType {
@@ -128,6 +201,11 @@ impl Variant {
         fn write_var_sys = write_sys;
     }
 
+    /// Like `var_sys()`, but for operations which modify the variant (ensures no aliasing at the time of the call).
+    fn var_sys_mut(&mut self) -> sys::GDNativeVariantPtr {
+        &mut self.opaque as *mut _ as sys::GDNativeVariantPtr
+    }
+
     /*#[doc(hidden)]
     pub unsafe fn from_var_sys_init(init_fn: impl FnOnce(sys::GDNativeVariantPtr)) -> Self {
         // Can't use uninitialized pointer -- Variant implementation in C++ expects that on assignment,
@@ -143,11 +221,17 @@ impl Variant {
 impl GodotFfi for Variant {
     ffi_methods! {
         type sys::GDNativeTypePtr = *mut Opaque;
-        fn from_sys;
         fn sys;
         fn write_sys;
     }
 
+    unsafe fn from_sys(ptr: sys::GDNativeTypePtr) -> Self {
+        // The pointee is owned by Godot (e.g. a ptrcall argument), so copy it like impl_from_sys_as_copy!
+        Self::from_var_sys_init(|variant_ptr| {
+            interface_fn!(variant_new_copy)(variant_ptr, ptr as sys::GDNativeVariantPtr);
+        })
+    }
+
     unsafe fn from_sys_init(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Self {
         // Can't use uninitialized pointer -- Variant implementation in C++ expects that on assignment,
         // the target pointer is an initialized Variant
@@ -184,18 +268,42 @@ impl Default for Variant {
 
 impl PartialEq for Variant {
     fn eq(&self, other: &Self) -> bool {
-        Self::evaluate(self, other, VariantOperator::Equal)
-            .map(|v| v.to::<bool>())
-            .unwrap_or(false) // if there is no defined conversion, then they are non-equal
+        self.hash_compare(other)
     }
 }
 
-// impl Eq for Variant {}
-// impl PartialEq for Variant {
-//     fn eq(&self, other: &Self) -> bool {
-//         unsafe { sys::method_table().ope }
-//     }
-// }
+// hash_compare() is reflexive, also for NaN
+impl Eq for Variant {}
+
+impl PartialOrd for Variant {
+    /// Orders values of comparable types like GDScript's `<` and `>`; returns `None` for incomparable ones.
+    ///
+    /// To be consistent with `==`, values of different types which GDScript considers equal (like `1` and `1.0`)
+    /// are incomparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let is_true = |op| {
+            self.evaluate(other, op)
+                .map_or(false, |result| result.booleanize())
+        };
+
+        if self == other {
+            Some(Ordering::Equal)
+        } else if is_true(VariantOperator::Less) {
+            Some(Ordering::Less)
+        } else if is_true(VariantOperator::Greater) {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl Hash for Variant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash = unsafe { interface_fn!(variant_hash)(self.var_sys()) };
+        hash.hash(state)
+    }
+}
 
 impl fmt::Display for Variant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,92 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rust operators for `Variant`, delegating to Godot's operator evaluation.
+//!
+//! Like in GDScript, the result type depends on the operands, e.g. `int + float` yields `float`. Operators panic
+//! if they are not defined for the given operand types (or on integer division by zero); use the `checked_*`
+//! methods to handle this case.
+
+use super::*;
+
+macro_rules! impl_variant_binary_op {
+    ($Trait:ident, $method:ident, $checked:ident, $Op:ident, $symbol:literal) => {
+        impl Variant {
+            #[doc = concat!("Evaluates `self ", $symbol, " rhs`, or returns `None` if not defined for the operand types.")]
+            pub fn $checked(&self, rhs: &Variant) -> Option<Variant> {
+                self.evaluate(rhs, VariantOperator::$Op)
+            }
+        }
+
+        impl std::ops::$Trait<&Variant> for &Variant {
+            type Output = Variant;
+
+            fn $method(self, rhs: &Variant) -> Variant {
+                self.$checked(rhs).unwrap_or_else(|| {
+                    panic!(
+                        concat!("operator `", $symbol, "` is not defined for {:?} and {:?}"),
+                        self, rhs
+                    )
+                })
+            }
+        }
+
+        impl std::ops::$Trait for Variant {
+            type Output = Variant;
+
+            fn $method(self, rhs: Variant) -> Variant {
+                std::ops::$Trait::$method(&self, &rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_variant_unary_op {
+    ($Trait:ident, $method:ident, $checked:ident, $Op:ident, $symbol:literal) => {
+        impl Variant {
+            #[doc = concat!("Evaluates `", $symbol, "self`, or returns `None` if not defined for the operand type.")]
+            pub fn $checked(&self) -> Option<Variant> {
+                self.evaluate(&Variant::nil(), VariantOperator::$Op)
+            }
+        }
+
+        impl std::ops::$Trait for &Variant {
+            type Output = Variant;
+
+            fn $method(self) -> Variant {
+                self.$checked().unwrap_or_else(|| {
+                    panic!(
+                        concat!("operator `", $symbol, "` is not defined for {:?}"),
+                        self
+                    )
+                })
+            }
+        }
+
+        impl std::ops::$Trait for Variant {
+            type Output = Variant;
+
+            fn $method(self) -> Variant {
+                std::ops::$Trait::$method(&self)
+            }
+        }
+    };
+}
+
+impl_variant_binary_op!(Add, add, checked_add, Add, "+");
+impl_variant_binary_op!(Sub, sub, checked_sub, Subtract, "-");
+impl_variant_binary_op!(Mul, mul, checked_mul, Multiply, "*");
+impl_variant_binary_op!(Div, div, checked_div, Divide, "/");
+impl_variant_binary_op!(Rem, rem, checked_rem, Module, "%");
+impl_variant_binary_op!(BitAnd, bitand, checked_bitand, BitAnd, "&");
+impl_variant_binary_op!(BitOr, bitor, checked_bitor, BitOr, "|");
+impl_variant_binary_op!(BitXor, bitxor, checked_bitxor, BitXor, "^");
+impl_variant_binary_op!(Shl, shl, checked_shl, ShiftLeft, "<<");
+impl_variant_binary_op!(Shr, shr, checked_shr, ShiftRight, ">>");
+
+impl_variant_unary_op!(Neg, neg, checked_neg, Negate, "-");
+// Logical, not bitwise: `!variant` corresponds to GDScript's `not variant`
+impl_variant_unary_op!(Not, not, checked_not, Not, "!");
@@ -5,11 +5,16 @@
  */
 
 use godot_ffi as sys;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
 pub trait VariantMetadata {
     fn variant_type() -> sys::GDNativeVariantType;
 
+    /// Name of the Godot class for object types, empty for all other types.
+    fn class_name() -> &'static str {
+        ""
+    }
+
     fn property_info(property_name: &str) -> sys::GDNativePropertyInfo {
         let reg = unsafe { sys::get_registry() };
         sys::GDNativePropertyInfo {
@@ -29,6 +34,70 @@ pub trait VariantMetadata {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Conversion of `#[func]` parameters and return values in Godot's _ptrcall_ calling convention.
+///
+/// In a ptrcall, values are passed as pointers to the Godot type given by [`VariantMetadata::variant_type()`].
+/// Types with their own Godot representation delegate to [`sys::GodotFuncMarshal`], while Rust types such as
+/// `Vec<T>` or `HashMap<K, V>` are converted from and to their Godot counterpart (`Array`, `Dictionary`).
+///
+/// This trait is separate from `sys::GodotFuncMarshal`, because the orphan rule prevents implementing the latter for
+/// standard library types outside of `godot-ffi`.
+pub trait PtrcallMarshal: Sized {
+    /// Reads an argument. On failure, returns the reason why it cannot be stored in `Self`.
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String>;
+
+    /// Writes a return value, transferring ownership to Godot. On failure, returns the reason why it cannot be
+    /// represented in Godot.
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String>;
+}
+
+/// [`PtrcallMarshal::try_from_ptr()`] for types which Godot passes as themselves.
+pub(crate) unsafe fn try_from_ptr_sys<T>(ptr: sys::GDNativeTypePtr) -> Result<T, String>
+where
+    T: sys::GodotFuncMarshal,
+{
+    T::try_from_sys(ptr).map_err(|via| out_of_range(&via))
+}
+
+/// [`PtrcallMarshal::try_write_ptr()`] for types which Godot passes as themselves.
+pub(crate) unsafe fn try_write_ptr_sys<T>(value: T, dst: sys::GDNativeTypePtr) -> Result<(), String>
+where
+    T: sys::GodotFuncMarshal + Debug,
+{
+    value
+        .try_write_sys(dst)
+        .map_err(|value| out_of_range(&value))?;
+
+    // FIXME should be inc_ref instead of forget
+    std::mem::forget(value);
+    Ok(())
+}
+
+/// [`PtrcallMarshal::try_from_ptr()`] for types converted from the Godot type `Via`, e.g. `Vec<T>` from `Array`.
+pub(crate) unsafe fn try_from_ptr_via<Via, T>(ptr: sys::GDNativeTypePtr) -> Result<T, String>
+where
+    Via: PtrcallMarshal + ToVariant,
+    T: FromVariant,
+{
+    let via = Via::try_from_ptr(ptr)?;
+    T::try_from_variant(&via.to_variant()).map_err(|e| e.to_string())
+}
+
+/// [`PtrcallMarshal::try_write_ptr()`] for types converted to the Godot type `Via`, e.g. `Vec<T>` to `Array`.
+pub(crate) unsafe fn try_write_ptr_via<Via, T>(
+    value: T,
+    dst: sys::GDNativeTypePtr,
+) -> Result<(), String>
+where
+    Via: PtrcallMarshal + FromVariant,
+    T: ToVariant,
+{
+    let via = Via::try_from_variant(&value.to_variant()).map_err(|e| e.to_string())?;
+    via.try_write_ptr(dst)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 pub trait SignatureTuple {
     type Params;
     type Ret;
@@ -46,8 +115,8 @@ pub trait SignatureTuple {
         method_name: &str,
     );
 
-    // Note: this method imposes extra bounds on GodotFfi, which may not be implemented for user types.
-    // We could fall back to varcalls in such cases, and not require GodotFfi categorically.
+    // Note: this method imposes extra bounds on PtrcallMarshal, which may not be implemented for user types.
+    // We could fall back to varcalls in such cases, and not require PtrcallMarshal categorically.
     fn ptrcall<C: GodotClass>(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         args_ptr: *const sys::GDNativeTypePtr,
@@ -83,8 +152,8 @@ macro_rules! impl_signature_for_tuple {
     ) => {
         #[allow(unused_variables)]
         impl<$R, $($Pn,)*> SignatureTuple for ($R, $($Pn,)*)
-            where $R: VariantMetadata + ToVariant + sys::GodotFuncMarshal + Debug,
-               $( $Pn: VariantMetadata + FromVariant + sys::GodotFuncMarshal + Debug, )*
+            where $R: VariantMetadata + ToVariant + PtrcallMarshal + Debug,
+               $( $Pn: VariantMetadata + FromVariant + PtrcallMarshal + Debug, )*
         {
             type Params = ($($Pn,)*);
             type Ret = $R;
@@ -141,7 +210,7 @@ macro_rules! impl_signature_for_tuple {
                     {
                         let variant = unsafe { &*(*args_ptr.offset($n) as *mut Variant) }; // TODO from_var_sys
                         let arg = <$Pn as FromVariant>::try_from_variant(variant)
-                            .unwrap_or_else(|e| param_error::<$Pn>(method_name, $n, variant));
+                            .unwrap_or_else(|e| param_error::<$Pn>(method_name, $n, &e));
 
                         arg
                     },
@@ -169,31 +238,31 @@ macro_rules! impl_signature_for_tuple {
                 let mut instance = storage.get_mut();
 
 				let args = ( $(
-                    unsafe { <$Pn as sys::GodotFuncMarshal>::try_from_sys(*args_ptr.offset($n)) }
+                    unsafe { <$Pn as PtrcallMarshal>::try_from_ptr(*args_ptr.offset($n)) }
                         .unwrap_or_else(|e| param_error::<$Pn>(method_name, $n, &e)),
                 )* );
 
                 let ret_val = func(&mut *instance, args);
-				unsafe { <$R as sys::GodotFuncMarshal>::try_write_sys(&ret_val, ret) }
+				unsafe { <$R as PtrcallMarshal>::try_write_ptr(ret_val, ret) }
                     .unwrap_or_else(|e| return_error::<$R>(method_name, &e));
-
-                // FIXME should be inc_ref instead of forget
-				std::mem::forget(ret_val);
             }
         }
     };
 }
 
-fn param_error<P>(method_name: &str, index: i32, arg: &impl Debug) -> ! {
+fn param_error<P>(method_name: &str, index: i32, reason: &impl Display) -> ! {
     let param_ty = std::any::type_name::<P>();
-    panic!(
-        "{method_name}: parameter [{index}] has type {param_ty}, which is unable to store argument {arg:?}",
-    );
+    panic!("{method_name}: parameter [{index}] has type {param_ty}, which is unable to store argument; {reason}");
 }
 
-fn return_error<R>(method_name: &str, arg: &impl Debug) -> ! {
+fn return_error<R>(method_name: &str, reason: &impl Display) -> ! {
     let return_ty = std::any::type_name::<R>();
-    panic!("{method_name}: return type {return_ty} is unable to store value {arg:?}",);
+    panic!("{method_name}: return type {return_ty} is unable to store value; {reason}");
+}
+
+/// Reason for failed conversions through [`sys::GodotFuncMarshal`], which only occur when narrowing integers.
+fn out_of_range(value: &impl Debug) -> String {
+    format!("value {value:?} is out of range")
 }
 
 impl_signature_for_tuple!(R);
@@ -4,8 +4,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::Variant;
-use crate::obj::EngineEnum;
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{Variant, VariantType};
 
 pub trait FromVariant: Sized {
     fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError>;
@@ -14,7 +16,7 @@ pub trait FromVariant: Sized {
     fn from_variant(variant: &Variant) -> Self {
         Self::try_from_variant(variant).unwrap_or_else(|e| {
             panic!(
-                "failed to convert from variant {:?} to {}; {:?}",
+                "failed to convert from variant {:?} to {}; {}",
                 variant,
                 std::any::type_name::<Self>(),
                 e
@@ -41,27 +43,84 @@ pub trait ToVariant {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-#[derive(Debug)]
-pub struct VariantConversionError;
-/*pub enum VariantConversionError {
-    /// Variant type does not match expected type
-    BadType,
+/// Error when converting a [`Variant`] to a Rust type, see [`FromVariant`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VariantConversionError {
+    expected: VariantType,
+    actual: VariantType,
+    value: String,
+    reason: VariantConversionReason,
+}
 
-    /// Variant value cannot be represented in target type
-    BadValue,
-}*/
+impl VariantConversionError {
+    /// Creates an error for a failed conversion of `variant` to a type stored as `expected` in variants.
+    pub fn new(expected: VariantType, variant: &Variant, reason: VariantConversionReason) -> Self {
+        Self {
+            expected,
+            actual: variant.get_type(),
+            value: variant.to_string(),
+            reason,
+        }
+    }
 
-// ----------------------------------------------------------------------------------------------------------------------------------------------
+    /// Variant type of the conversion target.
+    pub fn expected(&self) -> VariantType {
+        self.expected
+    }
 
-impl<T: EngineEnum> ToVariant for T {
-    fn to_variant(&self) -> Variant {
-        <i32 as ToVariant>::to_variant(&self.ord())
+    /// Variant type of the value which could not be converted.
+    pub fn actual(&self) -> VariantType {
+        self.actual
+    }
+
+    /// String representation of the value which could not be converted.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn reason(&self) -> VariantConversionReason {
+        self.reason
     }
 }
 
-impl<T: EngineEnum> FromVariant for T {
-    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-        <i32 as FromVariant>::try_from_variant(variant)
-            .and_then(|int| Self::try_from_ord(int).ok_or(VariantConversionError))
+impl fmt::Display for VariantConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot convert {:?} value `{}` to {:?}: {}",
+            self.actual, self.value, self.expected, self.reason
+        )
+    }
+}
+
+impl Error for VariantConversionError {}
+
+/// Why a [`VariantConversionError`] occurred.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum VariantConversionReason {
+    /// The variant holds a different type than the target type.
+    BadType,
+
+    /// The value has a suitable type, but cannot be represented in the target type, e.g. an integer overflow or an
+    /// unknown enum ordinal.
+    OutOfRange,
+
+    /// An object was expected, but the variant holds null.
+    NullObject,
+
+    /// The object is not an instance of `expected_class` or one of its subclasses.
+    WrongClass { expected_class: &'static str },
+}
+
+impl fmt::Display for VariantConversionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadType => write!(f, "type mismatch"),
+            Self::OutOfRange => write!(f, "value out of range"),
+            Self::NullObject => write!(f, "object is null"),
+            Self::WrongClass { expected_class } => {
+                write!(f, "object is not an instance of class {expected_class}")
+            }
+        }
     }
 }
@@ -7,6 +7,8 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
+use crate::builtin::math;
+
 type Inner = glam::f32::Vec2;
 //type Inner = glam::f64::DVec2;
 
@@ -17,33 +19,250 @@ pub struct Vector2 {
 }
 
 impl Vector2 {
-    pub fn new(x: f32, y: f32) -> Self {
+    /// Zero vector `(0, 0)`.
+    pub const ZERO: Self = Self::from_inner(Inner::ZERO);
+
+    /// One vector `(1, 1)`.
+    pub const ONE: Self = Self::from_inner(Inner::ONE);
+
+    /// Unit vector pointing left, `(-1, 0)`.
+    pub const LEFT: Self = Self::new(-1.0, 0.0);
+
+    /// Unit vector pointing right, `(1, 0)`.
+    pub const RIGHT: Self = Self::new(1.0, 0.0);
+
+    /// Unit vector pointing up, `(0, -1)`. Note that Y points down in 2D.
+    pub const UP: Self = Self::new(0.0, -1.0);
+
+    /// Unit vector pointing down, `(0, 1)`. Note that Y points down in 2D.
+    pub const DOWN: Self = Self::new(0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32) -> Self {
         Self {
             inner: Inner::new(x, y),
         }
     }
 
-    pub fn from_inner(inner: Inner) -> Self {
+    pub const fn from_inner(inner: Inner) -> Self {
         Self { inner }
     }
 
+    /// Converts an integer vector to floats.
+    pub fn from_vector2i(v: Vector2i) -> Self {
+        Self::from_inner(v.inner.as_vec2())
+    }
+
     /// only for testing
     pub fn inner(self) -> Inner {
         self.inner
     }
 
-    // Hacks for example
-    // pub fn length(self) -> f32 {
-    //     self.inner.length()
-    // }
-    // pub fn normalized(self) -> Vector2 {
-    //     Self::from_inner(self.inner.normalize())
-    // }
+    pub fn x(self) -> f32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> f32 {
+        self.inner.y
+    }
+
+    pub fn set_x(&mut self, x: f32) {
+        self.inner.x = x;
+    }
+
+    pub fn set_y(&mut self, y: f32) {
+        self.inner.y = y;
+    }
+
+    pub fn length(self) -> f32 {
+        self.inner.length()
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.inner.length_squared()
+    }
+
+    /// Returns the vector scaled to unit length, or the zero vector if the length is zero.
+    pub fn normalized(self) -> Self {
+        Self::from_inner(self.inner.normalize_or_zero())
+    }
+
+    pub fn is_normalized(self) -> bool {
+        math::is_equal_approx(self.length_squared(), 1.0)
+    }
+
+    pub fn dot(self, with: Self) -> f32 {
+        self.inner.dot(with.inner)
+    }
+
+    /// 2D cross product, i.e. the Z component of the 3D cross product of both vectors extended with `z = 0`.
+    pub fn cross(self, with: Self) -> f32 {
+        self.inner.perp_dot(with.inner)
+    }
+
+    pub fn distance_to(self, to: Self) -> f32 {
+        self.inner.distance(to.inner)
+    }
+
+    pub fn distance_squared_to(self, to: Self) -> f32 {
+        self.inner.distance_squared(to.inner)
+    }
+
+    /// Returns the normalized vector pointing from `self` to `to`.
+    pub fn direction_to(self, to: Self) -> Self {
+        (to - self).normalized()
+    }
+
+    /// Angle of this vector with respect to the positive X axis, in radians.
+    pub fn angle(self) -> f32 {
+        self.inner.y.atan2(self.inner.x)
+    }
+
+    /// Signed angle between `self` and `to`, in radians.
+    pub fn angle_to(self, to: Self) -> f32 {
+        self.cross(to).atan2(self.dot(to))
+    }
+
+    /// Angle of the line connecting `self` to the point `to`, in radians.
+    pub fn angle_to_point(self, to: Self) -> f32 {
+        (to - self).angle()
+    }
+
+    /// Returns the vector rotated by `angle` radians.
     pub fn rotated(self, angle: f32) -> Self {
-        Self::from_inner(glam::Affine2::from_angle(angle).transform_vector2(self.inner))
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            self.inner.x * cos - self.inner.y * sin,
+            self.inner.x * sin + self.inner.y * cos,
+        )
+    }
+
+    /// Returns a perpendicular vector rotated 90 degrees counter-clockwise, with the same length.
+    pub fn orthogonal(self) -> Self {
+        Self::new(self.inner.y, -self.inner.x)
+    }
+
+    pub fn lerp(self, to: Self, weight: f32) -> Self {
+        Self::from_inner(self.inner.lerp(to.inner, weight))
+    }
+
+    /// Spherical linear interpolation: interpolates the angle and the length separately.
+    ///
+    /// Falls back to [`lerp()`][Self::lerp] if either vector has zero length.
+    pub fn slerp(self, to: Self, weight: f32) -> Self {
+        let start_length_sq = self.length_squared();
+        let end_length_sq = to.length_squared();
+        if start_length_sq == 0.0 || end_length_sq == 0.0 {
+            // Zero-length vectors have no angle, so the best we can do is either lerp or throw an error
+            return self.lerp(to, weight);
+        }
+
+        let start_length = start_length_sq.sqrt();
+        let result_length = math::lerp(start_length, end_length_sq.sqrt(), weight);
+        let angle = self.angle_to(to);
+
+        self.rotated(angle * weight) * (result_length / start_length)
+    }
+
+    /// Moves `self` toward `to` by at most `delta`, without overshooting.
+    pub fn move_toward(self, to: Self, delta: f32) -> Self {
+        let diff = to - self;
+        let length = diff.length();
+
+        if length <= delta || length < math::CMP_EPSILON {
+            to
+        } else {
+            self + diff / length * delta
+        }
+    }
+
+    /// Projects `self` onto the vector `onto`.
+    pub fn project(self, onto: Self) -> Self {
+        onto * (self.dot(onto) / onto.length_squared())
+    }
+
+    /// Reflects `self` off the plane defined by the (normalized) `normal`.
+    pub fn reflect(self, normal: Self) -> Self {
+        normal * (2.0 * self.dot(normal)) - self
+    }
+
+    /// Bounces `self` off the plane defined by the (normalized) `normal`.
+    pub fn bounce(self, normal: Self) -> Self {
+        -self.reflect(normal)
+    }
+
+    /// Slides `self` along the plane defined by the (normalized) `normal`.
+    pub fn slide(self, normal: Self) -> Self {
+        self - normal * self.dot(normal)
+    }
+
+    /// Snaps each component to the nearest multiple of the corresponding component in `step`.
+    pub fn snapped(self, step: Self) -> Self {
+        Self::new(
+            math::snapped(self.inner.x, step.inner.x),
+            math::snapped(self.inner.y, step.inner.y),
+        )
+    }
+
+    /// Component-wise clamp between `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_inner(self.inner.clamp(min.inner, max.inner))
+    }
+
+    /// Returns the vector with its length limited to at most `length`.
+    pub fn limit_length(self, length: f32) -> Self {
+        Self::from_inner(self.inner.clamp_length_max(length))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::from_inner(self.inner.abs())
+    }
+
+    /// Component-wise sign, mapping zero to zero.
+    pub fn sign(self) -> Self {
+        Self::new(math::sign(self.inner.x), math::sign(self.inner.y))
+    }
+
+    pub fn floor(self) -> Self {
+        Self::from_inner(self.inner.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self::from_inner(self.inner.ceil())
+    }
+
+    /// Rounds each component to the nearest integer, with halfway cases rounded away from zero.
+    pub fn round(self) -> Self {
+        Self::from_inner(self.inner.round())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_inner(self.inner.min(other.inner))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_inner(self.inner.max(other.inner))
+    }
+
+    /// Ratio `x / y`.
+    pub fn aspect(self) -> f32 {
+        self.inner.x / self.inner.y
+    }
+
+    /// Returns `true` if both components are approximately equal, see [`math::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        math::is_equal_approx(self.inner.x, other.inner.x)
+            && math::is_equal_approx(self.inner.y, other.inner.y)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.inner.is_finite()
     }
 }
 
+impl_vector_operators!(Vector2, f32);
+
 impl GodotFfi for Vector2 {
     ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
 }
@@ -58,20 +277,138 @@ impl std::fmt::Display for Vector2 {
 
 type IInner = glam::IVec2;
 
-#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[repr(C)]
 pub struct Vector2i {
     inner: IInner,
 }
 
 impl Vector2i {
-    pub fn new(x: i32, y: i32) -> Self {
+    /// Zero vector `(0, 0)`.
+    pub const ZERO: Self = Self::from_inner(IInner::ZERO);
+
+    /// One vector `(1, 1)`.
+    pub const ONE: Self = Self::from_inner(IInner::ONE);
+
+    /// Unit vector pointing left, `(-1, 0)`.
+    pub const LEFT: Self = Self::new(-1, 0);
+
+    /// Unit vector pointing right, `(1, 0)`.
+    pub const RIGHT: Self = Self::new(1, 0);
+
+    /// Unit vector pointing up, `(0, -1)`. Note that Y points down in 2D.
+    pub const UP: Self = Self::new(0, -1);
+
+    /// Unit vector pointing down, `(0, 1)`. Note that Y points down in 2D.
+    pub const DOWN: Self = Self::new(0, 1);
+
+    pub const fn new(x: i32, y: i32) -> Self {
         Self {
             inner: IInner::new(x, y),
         }
     }
+
+    pub const fn from_inner(inner: IInner) -> Self {
+        Self { inner }
+    }
+
+    /// Converts a float vector, truncating each component towards zero (like Godot's `Vector2i(Vector2)`).
+    pub fn from_vector2(v: Vector2) -> Self {
+        Self::from_inner(v.inner.as_ivec2())
+    }
+
+    /// Converts to the float vector [`Vector2`].
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2::from_vector2i(self)
+    }
+
+    /// only for testing
+    pub fn inner(self) -> IInner {
+        self.inner
+    }
+
+    pub fn x(self) -> i32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> i32 {
+        self.inner.y
+    }
+
+    pub fn set_x(&mut self, x: i32) {
+        self.inner.x = x;
+    }
+
+    pub fn set_y(&mut self, y: i32) {
+        self.inner.y = y;
+    }
+
+    /// Returns the component along `axis`.
+    pub fn get(self, axis: Vector2Axis) -> i32 {
+        match axis {
+            Vector2Axis::X => self.inner.x,
+            Vector2Axis::Y => self.inner.y,
+        }
+    }
+
+    pub fn length(self) -> f32 {
+        self.to_vector2().length()
+    }
+
+    /// Squared length, computed in `i64` and wrapping around like in Godot.
+    pub fn length_squared(self) -> i64 {
+        self.inner
+            .to_array()
+            .iter()
+            .map(|&c| i64::from(c) * i64::from(c))
+            .fold(0, i64::wrapping_add)
+    }
+
+    /// Returns the axis of the largest component. If both are equal, returns [`Vector2Axis::X`].
+    pub fn max_axis(self) -> Vector2Axis {
+        if self.inner.x < self.inner.y {
+            Vector2Axis::Y
+        } else {
+            Vector2Axis::X
+        }
+    }
+
+    /// Returns the axis of the smallest component. If both are equal, returns [`Vector2Axis::Y`].
+    pub fn min_axis(self) -> Vector2Axis {
+        if self.inner.x < self.inner.y {
+            Vector2Axis::X
+        } else {
+            Vector2Axis::Y
+        }
+    }
+
+    /// Component-wise clamp between `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_inner(self.inner.clamp(min.inner, max.inner))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::from_inner(self.inner.abs())
+    }
+
+    /// Component-wise sign: `-1`, `0` or `1`.
+    pub fn sign(self) -> Self {
+        Self::from_inner(self.inner.signum())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_inner(self.inner.min(other.inner))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_inner(self.inner.max(other.inner))
+    }
 }
 
+impl_integer_vector_operators!(Vector2i, i32);
+
 impl GodotFfi for Vector2i {
     ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
 }
@@ -81,3 +418,18 @@ impl std::fmt::Display for Vector2i {
         self.inner.fmt(f)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// TODO auto-generate this, alongside all the other builtin type's enums
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum Vector2Axis {
+    X,
+    Y,
+}
+
+impl GodotFfi for Vector2Axis {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
@@ -7,6 +7,8 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
+use crate::builtin::math;
+
 type Inner = glam::f32::Vec3;
 // type Inner = glam::f64::DVec3;
 
@@ -17,13 +19,268 @@ pub struct Vector3 {
 }
 
 impl Vector3 {
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+    /// Zero vector `(0, 0, 0)`.
+    pub const ZERO: Self = Self::from_inner(Inner::ZERO);
+
+    /// One vector `(1, 1, 1)`.
+    pub const ONE: Self = Self::from_inner(Inner::ONE);
+
+    /// Unit vector pointing left, `(-1, 0, 0)`.
+    pub const LEFT: Self = Self::new(-1.0, 0.0, 0.0);
+
+    /// Unit vector pointing right, `(1, 0, 0)`.
+    pub const RIGHT: Self = Self::new(1.0, 0.0, 0.0);
+
+    /// Unit vector pointing up, `(0, 1, 0)`.
+    pub const UP: Self = Self::new(0.0, 1.0, 0.0);
+
+    /// Unit vector pointing down, `(0, -1, 0)`.
+    pub const DOWN: Self = Self::new(0.0, -1.0, 0.0);
+
+    /// Unit vector pointing forward, `(0, 0, -1)`.
+    pub const FORWARD: Self = Self::new(0.0, 0.0, -1.0);
+
+    /// Unit vector pointing back, `(0, 0, 1)`.
+    pub const BACK: Self = Self::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Self {
             inner: Inner::new(x, y, z),
         }
     }
+
+    pub const fn from_inner(inner: Inner) -> Self {
+        Self { inner }
+    }
+
+    /// Converts an integer vector to floats.
+    pub fn from_vector3i(v: Vector3i) -> Self {
+        Self::from_inner(v.inner.as_vec3())
+    }
+
+    /// only for testing
+    pub fn inner(self) -> Inner {
+        self.inner
+    }
+
+    pub fn x(self) -> f32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> f32 {
+        self.inner.y
+    }
+
+    pub fn z(self) -> f32 {
+        self.inner.z
+    }
+
+    pub fn set_x(&mut self, x: f32) {
+        self.inner.x = x;
+    }
+
+    pub fn set_y(&mut self, y: f32) {
+        self.inner.y = y;
+    }
+
+    pub fn set_z(&mut self, z: f32) {
+        self.inner.z = z;
+    }
+
+    pub fn length(self) -> f32 {
+        self.inner.length()
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.inner.length_squared()
+    }
+
+    /// Returns the vector scaled to unit length, or the zero vector if the length is zero.
+    pub fn normalized(self) -> Self {
+        Self::from_inner(self.inner.normalize_or_zero())
+    }
+
+    pub fn is_normalized(self) -> bool {
+        math::is_equal_approx(self.length_squared(), 1.0)
+    }
+
+    pub fn dot(self, with: Self) -> f32 {
+        self.inner.dot(with.inner)
+    }
+
+    pub fn cross(self, with: Self) -> Self {
+        Self::from_inner(self.inner.cross(with.inner))
+    }
+
+    pub fn distance_to(self, to: Self) -> f32 {
+        self.inner.distance(to.inner)
+    }
+
+    pub fn distance_squared_to(self, to: Self) -> f32 {
+        self.inner.distance_squared(to.inner)
+    }
+
+    /// Returns the normalized vector pointing from `self` to `to`.
+    pub fn direction_to(self, to: Self) -> Self {
+        (to - self).normalized()
+    }
+
+    /// Unsigned angle between `self` and `to`, in radians.
+    pub fn angle_to(self, to: Self) -> f32 {
+        self.cross(to).length().atan2(self.dot(to))
+    }
+
+    /// Signed angle between `self` and `to`, in radians. The sign is positive in counter-clockwise
+    /// direction when viewed from the side specified by `axis`.
+    pub fn signed_angle_to(self, to: Self, axis: Self) -> f32 {
+        let cross = self.cross(to);
+        let unsigned_angle = cross.length().atan2(self.dot(to));
+        let sign = cross.dot(axis);
+
+        if sign < 0.0 {
+            -unsigned_angle
+        } else {
+            unsigned_angle
+        }
+    }
+
+    /// Returns the vector rotated around the (normalized) `axis` by `angle` radians.
+    pub fn rotated(self, axis: Self, angle: f32) -> Self {
+        let rotation = glam::Quat::from_axis_angle(axis.inner, angle);
+        Self::from_inner(rotation * self.inner)
+    }
+
+    pub fn lerp(self, to: Self, weight: f32) -> Self {
+        Self::from_inner(self.inner.lerp(to.inner, weight))
+    }
+
+    /// Spherical linear interpolation: interpolates the angle and the length separately.
+    ///
+    /// Falls back to [`lerp()`][Self::lerp] if either vector has zero length, or if both are parallel.
+    pub fn slerp(self, to: Self, weight: f32) -> Self {
+        let start_length_sq = self.length_squared();
+        let end_length_sq = to.length_squared();
+        if start_length_sq == 0.0 || end_length_sq == 0.0 {
+            // Zero-length vectors have no angle, so the best we can do is either lerp or throw an error
+            return self.lerp(to, weight);
+        }
+
+        let axis = self.cross(to);
+        let axis_length_sq = axis.length_squared();
+        if axis_length_sq == 0.0 {
+            // Colinear vectors have no rotation axis or angle between them, so the best we can do is lerp
+            return self.lerp(to, weight);
+        }
+
+        let axis = axis / axis_length_sq.sqrt();
+        let start_length = start_length_sq.sqrt();
+        let result_length = math::lerp(start_length, end_length_sq.sqrt(), weight);
+        let angle = self.angle_to(to);
+
+        self.rotated(axis, angle * weight) * (result_length / start_length)
+    }
+
+    /// Moves `self` toward `to` by at most `delta`, without overshooting.
+    pub fn move_toward(self, to: Self, delta: f32) -> Self {
+        let diff = to - self;
+        let length = diff.length();
+
+        if length <= delta || length < math::CMP_EPSILON {
+            to
+        } else {
+            self + diff / length * delta
+        }
+    }
+
+    /// Projects `self` onto the vector `onto`.
+    pub fn project(self, onto: Self) -> Self {
+        onto * (self.dot(onto) / onto.length_squared())
+    }
+
+    /// Reflects `self` off the plane defined by the (normalized) `normal`.
+    pub fn reflect(self, normal: Self) -> Self {
+        normal * (2.0 * self.dot(normal)) - self
+    }
+
+    /// Bounces `self` off the plane defined by the (normalized) `normal`.
+    pub fn bounce(self, normal: Self) -> Self {
+        -self.reflect(normal)
+    }
+
+    /// Slides `self` along the plane defined by the (normalized) `normal`.
+    pub fn slide(self, normal: Self) -> Self {
+        self - normal * self.dot(normal)
+    }
+
+    /// Snaps each component to the nearest multiple of the corresponding component in `step`.
+    pub fn snapped(self, step: Self) -> Self {
+        Self::new(
+            math::snapped(self.inner.x, step.inner.x),
+            math::snapped(self.inner.y, step.inner.y),
+            math::snapped(self.inner.z, step.inner.z),
+        )
+    }
+
+    /// Component-wise clamp between `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_inner(self.inner.clamp(min.inner, max.inner))
+    }
+
+    /// Returns the vector with its length limited to at most `length`.
+    pub fn limit_length(self, length: f32) -> Self {
+        Self::from_inner(self.inner.clamp_length_max(length))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::from_inner(self.inner.abs())
+    }
+
+    /// Component-wise sign, mapping zero to zero.
+    pub fn sign(self) -> Self {
+        Self::new(
+            math::sign(self.inner.x),
+            math::sign(self.inner.y),
+            math::sign(self.inner.z),
+        )
+    }
+
+    pub fn floor(self) -> Self {
+        Self::from_inner(self.inner.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self::from_inner(self.inner.ceil())
+    }
+
+    /// Rounds each component to the nearest integer, with halfway cases rounded away from zero.
+    pub fn round(self) -> Self {
+        Self::from_inner(self.inner.round())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_inner(self.inner.min(other.inner))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_inner(self.inner.max(other.inner))
+    }
+
+    /// Returns `true` if all components are approximately equal, see [`math::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        math::is_equal_approx(self.inner.x, other.inner.x)
+            && math::is_equal_approx(self.inner.y, other.inner.y)
+            && math::is_equal_approx(self.inner.z, other.inner.z)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.inner.is_finite()
+    }
 }
 
+impl_vector_operators!(Vector3, f32);
+
 impl GodotFfi for Vector3 {
     ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
 }
@@ -40,20 +297,167 @@ impl std::fmt::Display for Vector3 {
 
 type IInner = glam::IVec3;
 
-#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[repr(C)]
 pub struct Vector3i {
     inner: IInner,
 }
 
 impl Vector3i {
-    pub fn new(x: i32, y: i32, z: i32) -> Self {
+    /// Zero vector `(0, 0, 0)`.
+    pub const ZERO: Self = Self::from_inner(IInner::ZERO);
+
+    /// One vector `(1, 1, 1)`.
+    pub const ONE: Self = Self::from_inner(IInner::ONE);
+
+    /// Unit vector pointing left, `(-1, 0, 0)`.
+    pub const LEFT: Self = Self::new(-1, 0, 0);
+
+    /// Unit vector pointing right, `(1, 0, 0)`.
+    pub const RIGHT: Self = Self::new(1, 0, 0);
+
+    /// Unit vector pointing up, `(0, 1, 0)`.
+    pub const UP: Self = Self::new(0, 1, 0);
+
+    /// Unit vector pointing down, `(0, -1, 0)`.
+    pub const DOWN: Self = Self::new(0, -1, 0);
+
+    /// Unit vector pointing forward, `(0, 0, -1)`.
+    pub const FORWARD: Self = Self::new(0, 0, -1);
+
+    /// Unit vector pointing back, `(0, 0, 1)`.
+    pub const BACK: Self = Self::new(0, 0, 1);
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
         Self {
             inner: IInner::new(x, y, z),
         }
     }
+
+    pub const fn from_inner(inner: IInner) -> Self {
+        Self { inner }
+    }
+
+    /// Converts a float vector, truncating each component towards zero (like Godot's `Vector3i(Vector3)`).
+    pub fn from_vector3(v: Vector3) -> Self {
+        Self::from_inner(v.inner.as_ivec3())
+    }
+
+    /// Converts to the float vector [`Vector3`].
+    pub fn to_vector3(self) -> Vector3 {
+        Vector3::from_vector3i(self)
+    }
+
+    /// only for testing
+    pub fn inner(self) -> IInner {
+        self.inner
+    }
+
+    pub fn x(self) -> i32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> i32 {
+        self.inner.y
+    }
+
+    pub fn z(self) -> i32 {
+        self.inner.z
+    }
+
+    pub fn set_x(&mut self, x: i32) {
+        self.inner.x = x;
+    }
+
+    pub fn set_y(&mut self, y: i32) {
+        self.inner.y = y;
+    }
+
+    pub fn set_z(&mut self, z: i32) {
+        self.inner.z = z;
+    }
+
+    /// Returns the component along `axis`.
+    pub fn get(self, axis: Vector3Axis) -> i32 {
+        match axis {
+            Vector3Axis::X => self.inner.x,
+            Vector3Axis::Y => self.inner.y,
+            Vector3Axis::Z => self.inner.z,
+        }
+    }
+
+    pub fn length(self) -> f32 {
+        self.to_vector3().length()
+    }
+
+    /// Squared length as `i64`, see [`Vector2i::length_squared()`][crate::builtin::Vector2i::length_squared].
+    pub fn length_squared(self) -> i64 {
+        self.inner
+            .to_array()
+            .iter()
+            .map(|&c| i64::from(c) * i64::from(c))
+            .fold(0, i64::wrapping_add)
+    }
+
+    /// Returns the axis of the largest component. Ties are resolved like in Godot.
+    pub fn max_axis(self) -> Vector3Axis {
+        let IInner { x, y, z } = self.inner;
+        if x < y {
+            if y < z {
+                Vector3Axis::Z
+            } else {
+                Vector3Axis::Y
+            }
+        } else if x < z {
+            Vector3Axis::Z
+        } else {
+            Vector3Axis::X
+        }
+    }
+
+    /// Returns the axis of the smallest component. Ties are resolved like in Godot.
+    pub fn min_axis(self) -> Vector3Axis {
+        let IInner { x, y, z } = self.inner;
+        if x < y {
+            if x < z {
+                Vector3Axis::X
+            } else {
+                Vector3Axis::Z
+            }
+        } else if y < z {
+            Vector3Axis::Y
+        } else {
+            Vector3Axis::Z
+        }
+    }
+
+    /// Component-wise clamp between `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_inner(self.inner.clamp(min.inner, max.inner))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::from_inner(self.inner.abs())
+    }
+
+    /// Component-wise sign: `-1`, `0` or `1`.
+    pub fn sign(self) -> Self {
+        Self::from_inner(self.inner.signum())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_inner(self.inner.min(other.inner))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_inner(self.inner.max(other.inner))
+    }
 }
 
+impl_integer_vector_operators!(Vector3i, i32);
+
 impl GodotFfi for Vector3i {
     ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
 }
@@ -68,7 +472,7 @@ impl std::fmt::Display for Vector3i {
 
 // TODO auto-generate this, alongside all the other builtin type's enums
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[repr(i32)]
 pub enum Vector3Axis {
     X,
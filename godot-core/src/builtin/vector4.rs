@@ -7,6 +7,8 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
+use crate::builtin::math;
+
 type Inner = glam::f32::Vec4;
 //type Inner = glam::f64::DVec4;
 
@@ -17,13 +19,196 @@ pub struct Vector4 {
 }
 
 impl Vector4 {
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    /// Zero vector `(0, 0, 0, 0)`.
+    pub const ZERO: Self = Self::from_inner(Inner::ZERO);
+
+    /// One vector `(1, 1, 1, 1)`.
+    pub const ONE: Self = Self::from_inner(Inner::ONE);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self {
             inner: Inner::new(x, y, z, w),
         }
     }
+
+    pub const fn from_inner(inner: Inner) -> Self {
+        Self { inner }
+    }
+
+    /// Converts an integer vector to floats.
+    pub fn from_vector4i(v: Vector4i) -> Self {
+        Self::from_inner(v.inner.as_vec4())
+    }
+
+    /// only for testing
+    pub fn inner(self) -> Inner {
+        self.inner
+    }
+
+    pub fn x(self) -> f32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> f32 {
+        self.inner.y
+    }
+
+    pub fn z(self) -> f32 {
+        self.inner.z
+    }
+
+    pub fn w(self) -> f32 {
+        self.inner.w
+    }
+
+    pub fn set_x(&mut self, x: f32) {
+        self.inner.x = x;
+    }
+
+    pub fn set_y(&mut self, y: f32) {
+        self.inner.y = y;
+    }
+
+    pub fn set_z(&mut self, z: f32) {
+        self.inner.z = z;
+    }
+
+    pub fn set_w(&mut self, w: f32) {
+        self.inner.w = w;
+    }
+
+    pub fn length(self) -> f32 {
+        self.inner.length()
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.inner.length_squared()
+    }
+
+    /// Returns the vector scaled to unit length, or the zero vector if the length is zero.
+    pub fn normalized(self) -> Self {
+        Self::from_inner(self.inner.normalize_or_zero())
+    }
+
+    pub fn is_normalized(self) -> bool {
+        math::is_equal_approx(self.length_squared(), 1.0)
+    }
+
+    pub fn dot(self, with: Self) -> f32 {
+        self.inner.dot(with.inner)
+    }
+
+    pub fn distance_to(self, to: Self) -> f32 {
+        self.inner.distance(to.inner)
+    }
+
+    pub fn distance_squared_to(self, to: Self) -> f32 {
+        self.inner.distance_squared(to.inner)
+    }
+
+    /// Returns the normalized vector pointing from `self` to `to`.
+    pub fn direction_to(self, to: Self) -> Self {
+        (to - self).normalized()
+    }
+
+    pub fn lerp(self, to: Self, weight: f32) -> Self {
+        Self::from_inner(self.inner.lerp(to.inner, weight))
+    }
+
+    /// Moves `self` toward `to` by at most `delta`, without overshooting.
+    pub fn move_toward(self, to: Self, delta: f32) -> Self {
+        let diff = to - self;
+        let length = diff.length();
+
+        if length <= delta || length < math::CMP_EPSILON {
+            to
+        } else {
+            self + diff / length * delta
+        }
+    }
+
+    /// Projects `self` onto the vector `onto`.
+    pub fn project(self, onto: Self) -> Self {
+        onto * (self.dot(onto) / onto.length_squared())
+    }
+
+    /// Reflects `self` off the hyperplane defined by the (normalized) `normal`.
+    pub fn reflect(self, normal: Self) -> Self {
+        normal * (2.0 * self.dot(normal)) - self
+    }
+
+    /// Bounces `self` off the hyperplane defined by the (normalized) `normal`.
+    pub fn bounce(self, normal: Self) -> Self {
+        -self.reflect(normal)
+    }
+
+    /// Snaps each component to the nearest multiple of the corresponding component in `step`.
+    pub fn snapped(self, step: Self) -> Self {
+        Self::new(
+            math::snapped(self.inner.x, step.inner.x),
+            math::snapped(self.inner.y, step.inner.y),
+            math::snapped(self.inner.z, step.inner.z),
+            math::snapped(self.inner.w, step.inner.w),
+        )
+    }
+
+    /// Component-wise clamp between `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_inner(self.inner.clamp(min.inner, max.inner))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::from_inner(self.inner.abs())
+    }
+
+    /// Component-wise sign, mapping zero to zero.
+    pub fn sign(self) -> Self {
+        Self::new(
+            math::sign(self.inner.x),
+            math::sign(self.inner.y),
+            math::sign(self.inner.z),
+            math::sign(self.inner.w),
+        )
+    }
+
+    pub fn floor(self) -> Self {
+        Self::from_inner(self.inner.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self::from_inner(self.inner.ceil())
+    }
+
+    /// Rounds each component to the nearest integer, with halfway cases rounded away from zero.
+    pub fn round(self) -> Self {
+        Self::from_inner(self.inner.round())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_inner(self.inner.min(other.inner))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_inner(self.inner.max(other.inner))
+    }
+
+    /// Returns `true` if all components are approximately equal, see [`math::is_equal_approx()`].
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        math::is_equal_approx(self.inner.x, other.inner.x)
+            && math::is_equal_approx(self.inner.y, other.inner.y)
+            && math::is_equal_approx(self.inner.z, other.inner.z)
+            && math::is_equal_approx(self.inner.w, other.inner.w)
+    }
+
+    pub fn is_finite(self) -> bool {
+        self.inner.is_finite()
+    }
 }
 
+impl_vector_operators!(Vector4, f32);
+
 impl GodotFfi for Vector4 {
     ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
 }
@@ -34,22 +219,152 @@ impl std::fmt::Display for Vector4 {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 type IInner = glam::IVec4;
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[repr(C)]
 pub struct Vector4i {
     inner: IInner,
 }
 
 impl Vector4i {
-    pub fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+    /// Zero vector `(0, 0, 0, 0)`.
+    pub const ZERO: Self = Self::from_inner(IInner::ZERO);
+
+    /// One vector `(1, 1, 1, 1)`.
+    pub const ONE: Self = Self::from_inner(IInner::ONE);
+
+    pub const fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
         Self {
             inner: IInner::new(x, y, z, w),
         }
     }
+
+    pub const fn from_inner(inner: IInner) -> Self {
+        Self { inner }
+    }
+
+    /// Converts a float vector, truncating each component towards zero (like Godot's `Vector4i(Vector4)`).
+    pub fn from_vector4(v: Vector4) -> Self {
+        Self::from_inner(v.inner.as_ivec4())
+    }
+
+    /// Converts to the float vector [`Vector4`].
+    pub fn to_vector4(self) -> Vector4 {
+        Vector4::from_vector4i(self)
+    }
+
+    /// only for testing
+    pub fn inner(self) -> IInner {
+        self.inner
+    }
+
+    pub fn x(self) -> i32 {
+        self.inner.x
+    }
+
+    pub fn y(self) -> i32 {
+        self.inner.y
+    }
+
+    pub fn z(self) -> i32 {
+        self.inner.z
+    }
+
+    pub fn w(self) -> i32 {
+        self.inner.w
+    }
+
+    pub fn set_x(&mut self, x: i32) {
+        self.inner.x = x;
+    }
+
+    pub fn set_y(&mut self, y: i32) {
+        self.inner.y = y;
+    }
+
+    pub fn set_z(&mut self, z: i32) {
+        self.inner.z = z;
+    }
+
+    pub fn set_w(&mut self, w: i32) {
+        self.inner.w = w;
+    }
+
+    /// Returns the component along `axis`.
+    pub fn get(self, axis: Vector4Axis) -> i32 {
+        match axis {
+            Vector4Axis::X => self.inner.x,
+            Vector4Axis::Y => self.inner.y,
+            Vector4Axis::Z => self.inner.z,
+            Vector4Axis::W => self.inner.w,
+        }
+    }
+
+    pub fn length(self) -> f32 {
+        self.to_vector4().length()
+    }
+
+    /// Squared length as `i64`, see [`Vector2i::length_squared()`][crate::builtin::Vector2i::length_squared].
+    pub fn length_squared(self) -> i64 {
+        self.inner
+            .to_array()
+            .iter()
+            .map(|&c| i64::from(c) * i64::from(c))
+            .fold(0, i64::wrapping_add)
+    }
+
+    /// Returns the axis of the largest component. On ties, the first such axis is returned.
+    pub fn max_axis(self) -> Vector4Axis {
+        let mut axis = Vector4Axis::X;
+        for candidate in [Vector4Axis::Y, Vector4Axis::Z, Vector4Axis::W] {
+            if self.get(candidate) > self.get(axis) {
+                axis = candidate;
+            }
+        }
+        axis
+    }
+
+    /// Returns the axis of the smallest component. On ties, the first such axis is returned.
+    pub fn min_axis(self) -> Vector4Axis {
+        let mut axis = Vector4Axis::X;
+        for candidate in [Vector4Axis::Y, Vector4Axis::Z, Vector4Axis::W] {
+            if self.get(candidate) < self.get(axis) {
+                axis = candidate;
+            }
+        }
+        axis
+    }
+
+    /// Component-wise clamp between `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_inner(self.inner.clamp(min.inner, max.inner))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::from_inner(self.inner.abs())
+    }
+
+    /// Component-wise sign: `-1`, `0` or `1`.
+    pub fn sign(self) -> Self {
+        Self::from_inner(self.inner.signum())
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_inner(self.inner.min(other.inner))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_inner(self.inner.max(other.inner))
+    }
 }
 
+impl_integer_vector_operators!(Vector4i, i32);
+
 impl GodotFfi for Vector4i {
     ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
 }
@@ -59,3 +374,20 @@ impl std::fmt::Display for Vector4i {
         self.inner.fmt(f)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// TODO auto-generate this, alongside all the other builtin type's enums
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum Vector4Axis {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+impl GodotFfi for Vector4Axis {
+    ffi_methods! { type sys::GDNativeTypePtr = *mut Self; .. }
+}
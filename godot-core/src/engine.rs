@@ -59,7 +59,7 @@ pub trait NodeExt {
     {
         let path = path.into();
 
-        self.try_get_node_as(path).unwrap_or_else(|| {
+        self.try_get_node_as(&path).unwrap_or_else(|| {
             panic!(
                 "There is no node of type {ty} path `{path}`",
                 ty = T::CLASS_NAME
@@ -13,8 +13,12 @@ use godot_ffi as sys;
 use sys::types::OpaqueObject;
 use sys::{ffi_methods, interface_fn, static_assert_eq_size, GodotFfi};
 
-use crate::builtin::{FromVariant, ToVariant, Variant, VariantConversionError};
-use crate::builtin::{GodotString, VariantMetadata};
+use crate::builtin::{
+    try_from_ptr_sys, try_write_ptr_sys, GodotString, PtrcallMarshal, VariantMetadata,
+};
+use crate::builtin::{
+    FromVariant, ToVariant, Variant, VariantConversionError, VariantConversionReason, VariantType,
+};
 use crate::obj::dom::Domain as _;
 use crate::obj::mem::Memory as _;
 use crate::obj::{cap, dom, mem, GodotClass, Inherits, Share};
@@ -465,6 +469,16 @@ impl<T: GodotClass> GodotFfi for Gd<T> {
     ffi_methods! { type sys::GDNativeTypePtr = Opaque; .. }
 }
 
+impl<T: GodotClass> PtrcallMarshal for Gd<T> {
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_sys(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_sys(self, dst)
+    }
+}
+
 impl<T: GodotClass> Gd<T> {
     pub unsafe fn from_sys_init_opt(init_fn: impl FnOnce(sys::GDNativeTypePtr)) -> Option<Self> {
         // Note: see _call_native_mb_ret_obj() in godot-cpp, which does things quite different (e.g. querying the instance binding).
@@ -544,15 +558,32 @@ impl<T: GodotClass> Share for Gd<T> {
 
 impl<T: GodotClass> FromVariant for Gd<T> {
     fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-        let result = unsafe {
-            let result = Self::from_sys_init(|self_ptr| {
+        let error = |reason| VariantConversionError::new(VariantType::Object, variant, reason);
+
+        match variant.get_type() {
+            VariantType::Nil => return Err(error(VariantConversionReason::NullObject)),
+            // booleanize() is false for null and freed objects
+            VariantType::Object if !variant.booleanize() => {
+                return Err(error(VariantConversionReason::NullObject))
+            }
+            VariantType::Object => {}
+            _ => return Err(error(VariantConversionReason::BadType)),
+        }
+
+        // Convert to Object first, so that a Gd<T> only exists after checking the dynamic class
+        let object = unsafe {
+            let object = Gd::<engine::Object>::from_sys_init(|self_ptr| {
                 let converter = sys::method_table().object_from_variant;
                 converter(self_ptr, variant.var_sys());
             });
-            result.ready()
+            object.ready()
         };
 
-        Ok(result)
+        object.owned_cast::<T>().map_err(|_object| {
+            error(VariantConversionReason::WrongClass {
+                expected_class: T::CLASS_NAME,
+            })
+        })
     }
 }
 
@@ -607,13 +638,17 @@ impl<T: GodotClass> VariantMetadata for Gd<T> {
         sys::GDNATIVE_VARIANT_TYPE_OBJECT
     }
 
+    fn class_name() -> &'static str {
+        T::CLASS_NAME
+    }
+
     fn property_info(name: &str) -> sys::GDNativePropertyInfo {
         // Note: filling this information properly is important so that Godot can use ptrcalls instead of varcalls
         // (requires typed GDScript + sufficient information from the extension side)
         let reg = unsafe { sys::get_registry() };
 
         let property_name = reg.c_string(name);
-        let class_name = reg.c_string(T::CLASS_NAME);
+        let class_name = reg.c_string(Self::class_name());
 
         sys::GDNativePropertyInfo {
             type_: Self::variant_type(),
@@ -4,7 +4,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{FromVariant, ToVariant, Variant, VariantConversionError, VariantMetadata};
+use crate::builtin::{
+    try_from_ptr_sys, try_write_ptr_sys, FromVariant, PtrcallMarshal, ToVariant, Variant,
+    VariantConversionError, VariantConversionReason, VariantMetadata, VariantType,
+};
 use godot_ffi as sys;
 use godot_ffi::{ffi_methods, GodotFfi};
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
@@ -80,8 +83,15 @@ impl GodotFfi for InstanceId {
 
 impl FromVariant for InstanceId {
     fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-        i64::try_from_variant(variant)
-            .and_then(|i| InstanceId::try_from_i64(i).ok_or(VariantConversionError))
+        i64::try_from_variant(variant).and_then(|i| {
+            InstanceId::try_from_i64(i).ok_or_else(|| {
+                VariantConversionError::new(
+                    VariantType::Int,
+                    variant,
+                    VariantConversionReason::OutOfRange,
+                )
+            })
+        })
     }
 }
 
@@ -92,31 +102,6 @@ impl ToVariant for InstanceId {
     }
 }
 
-/*
-// Note: Option impl is only possible as long as `FromVariant` and `InstanceId` are in same crate.
-// (Rust rationale: if upstream crate later adds blanket `impl FromVariant for Option<T>`, this would collide)
-impl FromVariant for Option<InstanceId> {
-    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
-        if variant.is_nil() {
-            Ok(None)
-        } else {
-            // Should 0 in variant be mapped to None, or cause an error like now?
-            i64::try_from_variant(variant).map(|i| InstanceId::try_from_i64(i))
-        }
-    }
-}
-
-impl ToVariant for Option<InstanceId> {
-    fn to_variant(&self) -> Variant {
-        if let Some(id) = self {
-            id.to_variant()
-        } else {
-            0i64.to_variant()
-        }
-    }
-}
-*/
-
 impl VariantMetadata for InstanceId {
     fn variant_type() -> sys::GDNativeVariantType {
         sys::GDNATIVE_VARIANT_TYPE_INT
@@ -126,3 +111,13 @@ impl VariantMetadata for InstanceId {
         sys::GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_INT64
     }
 }
+
+impl PtrcallMarshal for InstanceId {
+    unsafe fn try_from_ptr(ptr: sys::GDNativeTypePtr) -> Result<Self, String> {
+        try_from_ptr_sys(ptr)
+    }
+
+    unsafe fn try_write_ptr(self, dst: sys::GDNativeTypePtr) -> Result<(), String> {
+        try_write_ptr_sys(self, dst)
+    }
+}
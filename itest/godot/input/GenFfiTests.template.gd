@@ -11,6 +11,9 @@ func run() -> bool:
 	if !test_varcall_IDENT(ffi):
 		ok = false
 		push_error("  -- FFI test failed: test_varcall_IDENT")
+	if !test_ptrcall_IDENT(ffi):
+		ok = false
+		push_error("  -- FFI test failed: test_ptrcall_IDENT")
 	#)
 
 	print("[GD] GenFfi destructing...")
@@ -27,3 +30,16 @@ func test_varcall_IDENT(ffi: GenFfi) -> bool:
 
 	return ok1 && ok2
 #)
+
+# Static types let GDScript use ptrcalls
+#(
+func test_ptrcall_IDENT(ffi: GenFfi) -> bool:
+	var from_rust: TYPE = ffi.return_IDENT()
+	var ok1: bool = ffi.accept_IDENT(from_rust)
+
+	var from_gdscript: TYPE = VAL
+	var mirrored: TYPE = ffi.mirror_IDENT(from_gdscript)
+	var ok2: bool = (mirrored == from_gdscript)
+
+	return ok1 && ok2
+#)
@@ -12,13 +12,22 @@ use std::path::Path;
 type IoResult = std::io::Result<()>;
 
 macro_rules! push {
+    // Explicit identifier, for Rust types which are tested with several values
+    ($inputs:ident; $ident:ident: $gdscript_ty:ident, $rust_ty:ty, $gdscript_val:expr, $rust_val:expr) => {
+        push!(@input $inputs; stringify!($ident).to_string(), $gdscript_ty, $rust_ty, $gdscript_val, $rust_val);
+    };
+
     ($inputs:ident; $gdscript_ty:ident, $rust_ty:ty, $val:expr) => {
         push!($inputs; $gdscript_ty, $rust_ty, $val, $val);
     };
 
     ($inputs:ident; $gdscript_ty:ident, $rust_ty:ty, $gdscript_val:expr, $rust_val:expr) => {
+        push!(@input $inputs; make_ident(stringify!($rust_ty)), $gdscript_ty, $rust_ty, $gdscript_val, $rust_val);
+    };
+
+    (@input $inputs:ident; $ident:expr, $gdscript_ty:ident, $rust_ty:ty, $gdscript_val:expr, $rust_val:expr) => {
         $inputs.push(Input {
-            ident: stringify!($rust_ty).to_ascii_lowercase(),
+            ident: $ident,
             gdscript_ty: stringify!($gdscript_ty),
             gdscript_val: stringify!($gdscript_val),
             rust_ty: quote! { $rust_ty },
@@ -46,11 +55,31 @@ fn collect_inputs() -> Vec<Input> {
     push!(inputs; Vector4, Vector4, Vector4(-18.5, 24.75, -1.25, 777.875), Vector4::new(-18.5, 24.75, -1.25, 777.875));
     push!(inputs; Vector2i, Vector2i, Vector2i(-2147483648, 2147483647), Vector2i::new(-2147483648, 2147483647));
     push!(inputs; Vector3i, Vector3i, Vector3i(-1, -2147483648, 2147483647), Vector3i::new(-1, -2147483648, 2147483647));
+    push!(inputs; Vector4i, Vector4i, Vector4i(-1, -2147483648, 2147483647, 1000), Vector4i::new(-1, -2147483648, 2147483647, 1000));
+    push!(inputs; Rect2, Rect2, Rect2(-2.5, 7.75, 100.0, 0.5), Rect2::from_components(-2.5, 7.75, 100.0, 0.5));
+    push!(inputs; Rect2i, Rect2i, Rect2i(-1, 0, 2147483647, 1), Rect2i::from_components(-1, 0, 2147483647, 1));
+    push!(inputs; Plane, Plane, Plane(0.6, 0.8, 0.0, -12.5), Plane::new(Vector3::new(0.6, 0.8, 0.0), -12.5));
+    push!(inputs; AABB, AABB, AABB(Vector3(-1.5, 2.0, 0.25), Vector3(8.0, 0.5, 3.75)), AABB::new(Vector3::new(-1.5, 2.0, 0.25), Vector3::new(8.0, 0.5, 3.75)));
+    push!(inputs; Quaternion, Quaternion, Quaternion(0.5, -0.5, 0.5, -0.5), Quaternion::new(0.5, -0.5, 0.5, -0.5));
+    push!(inputs; Basis, Basis, Basis(Vector3(1.0, 2.0, 3.0), Vector3(-4.5, 0.0, 0.25), Vector3(0.0, 8.0, -16.0)), Basis::from_cols(Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.5, 0.0, 0.25), Vector3::new(0.0, 8.0, -16.0)));
+    push!(inputs; Transform2D, Transform2D, Transform2D(Vector2(1.0, 2.0), Vector2(-0.5, 0.25), Vector2(100.0, -8.0)), Transform2D::new(Vector2::new(1.0, 2.0), Vector2::new(-0.5, 0.25), Vector2::new(100.0, -8.0)));
+    push!(inputs; Transform3D, Transform3D, Transform3D(Vector3(1.0, 2.0, 3.0), Vector3(-4.5, 0.0, 0.25), Vector3(0.0, 8.0, -16.0), Vector3(7.0, -7.0, 0.5)), Transform3D::from_cols(Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.5, 0.0, 0.25), Vector3::new(0.0, 8.0, -16.0), Vector3::new(7.0, -7.0, 0.5)));
+    push!(inputs; Projection, Projection, Projection(Vector4(1.0, 2.0, 3.0, 4.0), Vector4(-4.5, 0.0, 0.25, 1.0), Vector4(0.0, 8.0, -16.0, -1.0), Vector4(7.0, -7.0, 0.5, 0.0)), Projection::from_cols(Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(-4.5, 0.0, 0.25, 1.0), Vector4::new(0.0, 8.0, -16.0, -1.0), Vector4::new(7.0, -7.0, 0.5, 0.0)));
     //push!(inputs; Variant, Variant, 123, 123i64.to_variant());
 
     // Composite
     push!(inputs; int, InstanceId, -1, InstanceId::from_nonzero(0xFFFFFFFFFFFFFFF));
 
+    // Rust standard types
+    push!(inputs; Variant, Option<i64>, 5, Some(5));
+    push!(inputs; option_i64_none: Variant, Option<i64>, null, None);
+    push!(inputs; Variant, Option<GodotString>, "hello", Some("hello".into()));
+    push!(inputs; option_godot_string_none: Variant, Option<GodotString>, null, None);
+    push!(inputs; Variant, Option<Vec<i64>>, [1, -2], Some(vec![1, -2]));
+    push!(inputs; Array, Vec<i64>, [1, -2, 3], vec![1, -2, 3]);
+    push!(inputs; Array, [f64; 2], [0.5, -1.25], [0.5, -1.25]);
+    push!(inputs; Array, (i64, GodotString), [7, "seven"], (7, "seven".into()));
+
     inputs
 }
 
@@ -116,6 +145,16 @@ fn rustfmt_if_needed(out_files: Vec<std::path::PathBuf>) {
     }
 }
 
+/// Turns a Rust type into an identifier for method names, e.g. `Vec<i64>` into `vec_i64`.
+fn make_ident(rust_ty: &str) -> String {
+    rust_ty
+        .to_ascii_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
 struct Input {
     ident: String,
     gdscript_ty: &'static str,
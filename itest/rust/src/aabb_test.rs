@@ -0,0 +1,117 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Vector3, AABB};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= aabb_basics();
+    ok &= aabb_intersection();
+    ok &= aabb_support_and_endpoints();
+    ok &= aabb_intersects_ray();
+    ok &= aabb_intersects_segment();
+    ok
+}
+
+fn unit_box() -> AABB {
+    AABB::new(Vector3::ZERO, Vector3::ONE)
+}
+
+#[itest]
+fn aabb_basics() {
+    let aabb = AABB::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 4.0, 6.0));
+
+    assert_eq!(aabb.end(), Vector3::new(3.0, 6.0, 9.0));
+    assert_eq!(aabb.center(), Vector3::new(2.0, 4.0, 6.0));
+    assert_eq!(aabb.volume(), 48.0);
+    assert!(aabb.has_volume());
+    assert!(aabb.has_point(Vector3::new(3.0, 2.0, 5.0)));
+    assert!(!aabb.has_point(Vector3::new(3.1, 2.0, 5.0)));
+
+    let flipped = AABB::new(Vector3::new(3.0, 6.0, 9.0), Vector3::new(-2.0, -4.0, -6.0));
+    assert_eq!(flipped.abs(), aabb);
+    assert_eq!(
+        unit_box().grow(1.0),
+        AABB::new(-Vector3::ONE, Vector3::ONE * 3.0)
+    );
+    assert_eq!(
+        unit_box().expand(Vector3::new(2.0, -1.0, 0.5)),
+        AABB::new(Vector3::new(0.0, -1.0, 0.0), Vector3::new(2.0, 2.0, 1.0))
+    );
+}
+
+#[itest]
+fn aabb_intersection() {
+    let a = unit_box();
+    let b = AABB::new(Vector3::new(0.5, 0.5, 0.5), Vector3::ONE);
+    let touching = AABB::new(Vector3::new(1.0, 0.0, 0.0), Vector3::ONE);
+
+    assert!(a.intersects(b));
+    assert!(!a.intersects(touching));
+    assert_eq!(
+        a.intersection(b),
+        Some(AABB::new(Vector3::new(0.5, 0.5, 0.5), Vector3::ONE * 0.5))
+    );
+    assert_eq!(a.intersection(touching), None);
+
+    let merged = a.merge(touching);
+    assert_eq!(
+        merged,
+        AABB::new(Vector3::ZERO, Vector3::new(2.0, 1.0, 1.0))
+    );
+    assert!(merged.encloses(a));
+    assert!(!a.encloses(merged));
+}
+
+#[itest]
+fn aabb_support_and_endpoints() {
+    let aabb = AABB::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(2.0, 4.0, 6.0));
+
+    assert_eq!(
+        aabb.get_support(Vector3::new(1.0, -1.0, 1.0)),
+        Vector3::new(1.0, -2.0, 3.0)
+    );
+    assert_eq!(aabb.get_endpoint(0), aabb.position);
+    assert_eq!(aabb.get_endpoint(7), aabb.end());
+    assert_eq!(aabb.get_endpoint(4), Vector3::new(1.0, -2.0, -3.0));
+    assert_eq!(aabb.get_endpoint(1), Vector3::new(-1.0, -2.0, 3.0));
+}
+
+#[itest]
+fn aabb_intersects_ray() {
+    let aabb = unit_box();
+
+    assert_eq!(
+        aabb.intersects_ray(Vector3::new(-1.0, 0.5, 0.5), Vector3::RIGHT),
+        Some(Vector3::new(0.0, 0.5, 0.5))
+    );
+    assert_eq!(
+        aabb.intersects_ray(Vector3::new(-1.0, 0.5, 0.5), Vector3::LEFT),
+        None
+    );
+    assert_eq!(
+        aabb.intersects_ray(Vector3::new(-1.0, 2.0, 0.5), Vector3::RIGHT),
+        None
+    );
+
+    let inside = Vector3::new(0.5, 0.5, 0.5);
+    assert_eq!(aabb.intersects_ray(inside, Vector3::UP), Some(inside));
+}
+
+#[itest]
+fn aabb_intersects_segment() {
+    let aabb = unit_box();
+
+    assert_eq!(
+        aabb.intersects_segment(Vector3::new(0.5, 3.0, 0.5), Vector3::new(0.5, -3.0, 0.5)),
+        Some(Vector3::new(0.5, 1.0, 0.5))
+    );
+    assert_eq!(
+        aabb.intersects_segment(Vector3::new(0.5, 3.0, 0.5), Vector3::new(0.5, 2.0, 0.5)),
+        None
+    );
+}
@@ -0,0 +1,205 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::{expect_panic, itest};
+use godot::builtin::{Array, FromVariant, GodotString, ToVariant, Variant};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= array_default();
+    ok &= array_push_pop();
+    ok &= array_get_set();
+    ok &= array_insert_remove();
+    ok &= array_resize_clear();
+    ok &= array_find_contains();
+    ok &= array_reverse_sort();
+    ok &= array_slice();
+    ok &= array_clone_duplicate();
+    ok &= array_iter();
+    ok &= array_variant();
+    ok
+}
+
+fn ints(values: &[i64]) -> Array {
+    values.iter().copied().collect()
+}
+
+#[itest]
+fn array_default() {
+    let array = Array::new();
+
+    assert!(array.is_empty());
+    assert_eq!(array.len(), 0);
+    assert_eq!(array.get(0), None);
+    assert_eq!(array.front(), None);
+    assert_eq!(array.back(), None);
+    assert_eq!(array, Array::default());
+}
+
+#[itest]
+fn array_push_pop() {
+    let mut array = Array::new();
+    array.push(1);
+    array.push(GodotString::from("two"));
+
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.front(), Some(Variant::from(1)));
+    assert_eq!(array.back(), Some(GodotString::from("two").to_variant()));
+
+    assert_eq!(array.pop(), Some(GodotString::from("two").to_variant()));
+    assert_eq!(array.pop(), Some(Variant::from(1)));
+    assert_eq!(array.pop(), None);
+}
+
+#[itest]
+fn array_get_set() {
+    let mut array = ints(&[1, 2, 3]);
+
+    assert_eq!(array.get(2), Some(Variant::from(3)));
+    assert_eq!(array.get(3), None);
+
+    array.set(1, 20);
+    assert_eq!(array, ints(&[1, 20, 3]));
+
+    expect_panic("set out of bounds", move || array.set(3, 0));
+}
+
+#[itest]
+fn array_insert_remove() {
+    let mut array = ints(&[1, 3]);
+
+    array.insert(1, 2);
+    array.insert(3, 4);
+    assert_eq!(array, ints(&[1, 2, 3, 4]));
+
+    assert_eq!(array.remove(0), Variant::from(1));
+    assert_eq!(array.remove(2), Variant::from(4));
+    assert_eq!(array, ints(&[2, 3]));
+}
+
+#[itest]
+fn array_resize_clear() {
+    let mut array = ints(&[1, 2, 3]);
+
+    array.resize(5);
+    assert_eq!(array.len(), 5);
+    assert_eq!(array.get(4), Some(Variant::nil()));
+
+    array.resize(1);
+    assert_eq!(array, ints(&[1]));
+
+    array.clear();
+    assert!(array.is_empty());
+}
+
+#[itest]
+fn array_find_contains() {
+    let array = ints(&[5, 6, 5]);
+
+    assert_eq!(array.find(5), Some(0));
+    assert_eq!(array.find(6), Some(1));
+    assert_eq!(array.find(7), None);
+    assert!(array.contains(6));
+    assert!(!array.contains(GodotString::from("6")));
+}
+
+#[itest]
+fn array_reverse_sort() {
+    let mut array = ints(&[2, 3, 1]);
+
+    array.reverse();
+    assert_eq!(array, ints(&[1, 3, 2]));
+
+    array.sort();
+    assert_eq!(array, ints(&[1, 2, 3]));
+}
+
+#[itest]
+fn array_slice() {
+    let array = ints(&[0, 1, 2, 3, 4, 5]);
+
+    assert_eq!(array.slice_shallow(1, Some(4), None), ints(&[1, 2, 3]));
+    assert_eq!(array.slice_shallow(0, Some(6), Some(2)), ints(&[0, 2, 4]));
+    assert_eq!(array.slice_shallow(4, Some(100), None), ints(&[4, 5]));
+    assert_eq!(array.slice_shallow(2, None, None), ints(&[2, 3, 4, 5]));
+    assert_eq!(array.slice_shallow(5, Some(1), Some(-2)), ints(&[5, 3]));
+
+    // Reverse slices clamp `begin` to the last element and can reach the first one
+    assert_eq!(
+        array.slice_shallow(usize::MAX, None, Some(-1)),
+        ints(&[5, 4, 3, 2, 1, 0])
+    );
+    assert_eq!(array.slice_shallow(4, None, Some(-2)), ints(&[4, 2, 0]));
+    assert_eq!(array.slice_shallow(5, None, Some(-2)), ints(&[5, 3, 1]));
+    assert_eq!(array.slice_shallow(0, None, Some(-1)), ints(&[0]));
+
+    let empty = Array::new();
+    assert_eq!(
+        empty.slice_shallow(usize::MAX, None, Some(-1)),
+        Array::new()
+    );
+    assert_eq!(empty.slice_shallow(0, None, None), Array::new());
+
+    let nested: Array = [array.clone()].into_iter().collect();
+    let shallow = nested.slice_shallow(0, Some(1), None);
+    let deep = nested.slice_deep(0, None, Some(-1));
+
+    let mut shared = array;
+    shared.push(6);
+
+    let shallow_inner = Array::try_from_variant(&shallow.get(0).unwrap()).unwrap();
+    let deep_inner = Array::try_from_variant(&deep.get(0).unwrap()).unwrap();
+    assert_eq!(shallow_inner.len(), 7);
+    assert_eq!(deep_inner.len(), 6);
+}
+
+#[itest]
+fn array_clone_duplicate() {
+    let mut array = ints(&[1]);
+    let shared = array.clone();
+    let shallow = array.duplicate_shallow();
+
+    array.push(2);
+    assert_eq!(shared, ints(&[1, 2]));
+    assert_eq!(shallow, ints(&[1]));
+
+    let nested: Array = [array.clone()].into_iter().collect();
+    let deep = nested.duplicate_deep();
+    array.push(3);
+
+    let deep_inner = Array::try_from_variant(&deep.get(0).unwrap()).unwrap();
+    assert_eq!(deep_inner, ints(&[1, 2]));
+}
+
+#[itest]
+fn array_iter() {
+    let array = ints(&[1, 2, 3]);
+
+    let collected: Vec<Variant> = array.iter().collect();
+    assert_eq!(
+        collected,
+        vec![Variant::from(1), Variant::from(2), Variant::from(3)]
+    );
+
+    let mut sum = 0;
+    for value in &array {
+        sum += i64::try_from_variant(&value).unwrap();
+    }
+    assert_eq!(sum, 6);
+
+    let mut extended = Array::new();
+    extended.extend(collected);
+    assert_eq!(extended, array);
+}
+
+#[itest]
+fn array_variant() {
+    let array = ints(&[1, 2]);
+
+    let variant = array.to_variant();
+    let roundtrip = Array::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, array);
+}
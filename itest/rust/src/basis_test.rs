@@ -0,0 +1,137 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Basis, EulerOrder, Quaternion, Vector3};
+use std::f32::consts::FRAC_PI_2;
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= basis_rows_and_columns();
+    ok &= basis_multiplication();
+    ok &= basis_euler_orders();
+    ok &= basis_euler_simplest_form();
+    ok &= basis_inverse_and_determinant();
+    ok &= basis_orthonormalized();
+    ok &= basis_scale_and_rotation();
+    ok
+}
+
+#[itest]
+fn basis_rows_and_columns() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(4.0, 5.0, 6.0);
+    let c = Vector3::new(7.0, 8.0, 9.0);
+    let mut basis = Basis::from_cols(a, b, c);
+
+    assert_eq!(basis.column(1), b);
+    assert_eq!(basis.rows[0], Vector3::new(1.0, 4.0, 7.0));
+    assert_eq!(basis.transposed(), Basis::from_rows(a, b, c));
+
+    basis.set_column(2, Vector3::ZERO);
+    assert_eq!(basis.rows[2], Vector3::new(3.0, 6.0, 0.0));
+    assert_eq!(Basis::default(), Basis::IDENTITY);
+}
+
+#[itest]
+fn basis_multiplication() {
+    let rotation = Basis::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+    assert!((rotation * Vector3::RIGHT).is_equal_approx(Vector3::FORWARD));
+    assert!((rotation * rotation * Vector3::RIGHT).is_equal_approx(Vector3::LEFT));
+
+    let scale = Basis::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    assert_eq!(scale * Vector3::ONE, Vector3::new(2.0, 3.0, 4.0));
+    assert!((scale * rotation).is_equal_approx(rotation.scaled(Vector3::new(2.0, 3.0, 4.0))));
+}
+
+#[itest]
+fn basis_euler_orders() {
+    let euler = Vector3::new(0.4, -0.9, 1.3);
+    let orders = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    for order in orders {
+        let basis = Basis::from_euler(order, euler);
+        assert!(
+            basis.get_euler(order).is_equal_approx(euler),
+            "euler roundtrip for {order:?}"
+        );
+    }
+
+    // YXZ matches the quaternion convention
+    let basis = Basis::from_euler(EulerOrder::YXZ, euler);
+    let quat = Quaternion::from_euler(euler);
+    assert!(basis.is_equal_approx(Basis::from_quaternion(quat)));
+}
+
+#[itest]
+fn basis_euler_simplest_form() {
+    let pure_y = Basis::from_axis_angle(Vector3::UP, 2.5);
+    assert!(pure_y
+        .get_euler(EulerOrder::XYZ)
+        .is_equal_approx(Vector3::new(0.0, 2.5, 0.0)));
+
+    let pure_x = Basis::from_axis_angle(Vector3::RIGHT, 2.5);
+    assert!(pure_x
+        .get_euler(EulerOrder::YXZ)
+        .is_equal_approx(Vector3::new(2.5, 0.0, 0.0)));
+}
+
+#[itest]
+fn basis_inverse_and_determinant() {
+    let basis = Basis::from_cols(
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 4.0),
+    );
+
+    assert_eq!(basis.determinant(), 8.0);
+    assert!((basis * basis.inverse()).is_equal_approx(Basis::IDENTITY));
+    assert_eq!(
+        Basis::from_scale(Vector3::new(1.0, -1.0, 1.0)).determinant(),
+        -1.0
+    );
+}
+
+#[itest]
+fn basis_orthonormalized() {
+    let skewed = Basis::from_cols(
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(1.0, 3.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.5),
+    );
+    let orthonormal = skewed.orthonormalized();
+
+    assert!(orthonormal.is_equal_approx(Basis::IDENTITY));
+    assert!(is_equal_approx(orthonormal.determinant(), 1.0));
+}
+
+#[itest]
+fn basis_scale_and_rotation() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalized(), 0.8);
+    let basis = Basis::from_quaternion(rotation).scaled(Vector3::new(2.0, 2.0, 2.0));
+
+    assert!(basis
+        .get_scale()
+        .is_equal_approx(Vector3::new(2.0, 2.0, 2.0)));
+
+    let extracted = basis.get_rotation_quaternion();
+    assert!(extracted.is_equal_approx(rotation) || extracted.is_equal_approx(-rotation));
+
+    let rotated = Basis::IDENTITY.rotated(Vector3::UP, FRAC_PI_2);
+    assert!(rotated.is_equal_approx(Basis::from_axis_angle(Vector3::UP, FRAC_PI_2)));
+}
+
+fn is_equal_approx(a: f32, b: f32) -> bool {
+    godot::builtin::math::is_equal_approx(a, b)
+}
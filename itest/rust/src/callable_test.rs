@@ -0,0 +1,112 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Callable, FromVariant, StringName, ToVariant, Variant, Vector3};
+use godot::engine::Node3D;
+use godot::obj::Share;
+use std::collections::HashSet;
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= callable_invalid();
+    ok &= callable_target();
+    ok &= callable_call();
+    ok &= callable_bind_unbind();
+    ok &= callable_eq_hash();
+    ok &= callable_variant();
+    ok
+}
+
+#[itest]
+fn callable_invalid() {
+    let callable = Callable::invalid();
+
+    assert!(callable.is_null());
+    assert!(!callable.is_valid());
+    assert_eq!(callable.get_object_id(), None);
+    assert!(callable.get_object().is_none());
+}
+
+#[itest]
+fn callable_target() {
+    let node = Node3D::new_alloc();
+    let callable = Callable::from_object_method(node.share(), "set_position");
+
+    assert!(!callable.is_null());
+    assert!(callable.is_valid());
+    assert_eq!(callable.get_method(), StringName::from("set_position"));
+    assert_eq!(callable.get_object_id(), Some(node.instance_id()));
+
+    let object = callable.get_object().expect("target object");
+    assert_eq!(object.instance_id(), node.instance_id());
+
+    node.free();
+    assert!(!callable.is_valid());
+    assert!(callable.get_object().is_none());
+}
+
+#[itest]
+fn callable_call() {
+    let node = Node3D::new_alloc();
+    let setter = Callable::from_object_method(node.share(), "set_position");
+    let getter = Callable::from_object_method(node.share(), "get_position");
+
+    let pos = Vector3::new(1.0, -2.5, 3.0);
+    let result = setter.call(&[pos.to_variant()]);
+
+    assert_eq!(result, Variant::nil());
+    assert_eq!(getter.call(&[]), pos.to_variant());
+
+    node.free();
+}
+
+#[itest]
+fn callable_bind_unbind() {
+    let node = Node3D::new_alloc();
+    let pos = Vector3::new(4.0, 5.0, 6.0);
+
+    let setter = Callable::from_object_method(node.share(), "set_position");
+    let bound = setter.bind(&[pos.to_variant()]);
+    bound.call(&[]);
+    assert_eq!(node.get_position(), pos);
+
+    let getter = Callable::from_object_method(node.share(), "get_position");
+    let unbound = getter.unbind(2);
+    let result = unbound.call(&[Variant::from(1), Variant::from(2)]);
+    assert_eq!(result, pos.to_variant());
+
+    node.free();
+}
+
+#[itest]
+fn callable_eq_hash() {
+    let node = Node3D::new_alloc();
+    let first = Callable::from_object_method(node.share(), "get_position");
+    let second = Callable::from_object_method(node.share(), "get_position");
+    let different = Callable::from_object_method(node.share(), "set_position");
+
+    assert_eq!(first, second);
+    assert_ne!(first, different);
+
+    let set: HashSet<Callable> = [first, second, different].into_iter().collect();
+    assert_eq!(set.len(), 2);
+
+    node.free();
+}
+
+#[itest]
+fn callable_variant() {
+    let node = Node3D::new_alloc();
+    let callable = Callable::from_object_method(node.share(), "get_position");
+
+    let variant = callable.to_variant();
+    let roundtrip = Callable::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, callable);
+    assert_eq!(roundtrip.get_method(), StringName::from("get_position"));
+
+    node.free();
+}
@@ -0,0 +1,170 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{math, Color};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= color_html();
+    ok &= color_packing();
+    ok &= color_hsv();
+    ok &= color_ok_hsl();
+    ok &= color_blending();
+    ok &= color_srgb();
+    ok &= color_named();
+    ok
+}
+
+#[itest]
+fn color_html() {
+    let orange = Color::new(1.0, 0.5019608, 0.0, 1.0);
+
+    assert_eq!(Color::from_html("#ff8000"), Some(orange));
+    assert_eq!(Color::from_html("FF8000ff"), Some(orange));
+    assert_eq!(Color::from_html("#f80"), Color::from_html("#ff8800"));
+    assert_eq!(
+        Color::from_html("f808"),
+        Some(Color::new(1.0, 0.53333336, 0.0, 0.53333336))
+    );
+
+    assert_eq!(Color::from_html(""), None);
+    assert_eq!(
+        Color::from_html("#ff80"),
+        Some(Color::new(1.0, 1.0, 0.53333336, 0.0))
+    );
+    assert_eq!(Color::from_html("#ff800"), None);
+    assert_eq!(Color::from_html("#gg8000"), None);
+    assert_eq!(Color::from_html("#ff80ä"), None);
+
+    assert_eq!(orange.to_html(false), "ff8000");
+    assert_eq!(orange.to_html(true), "ff8000ff");
+    assert_eq!(Color::new(2.0, -1.0, 0.2, 0.0).to_html(true), "ff003300");
+}
+
+#[itest]
+fn color_packing() {
+    let color = Color::from_rgba8(0x12, 0x34, 0x56, 0x78);
+
+    assert_eq!(color.to_rgba32(), 0x12345678);
+    assert_eq!(color.to_argb32(), 0x78123456);
+    assert_eq!(color.to_abgr32(), 0x78563412);
+    assert_eq!(Color::from_rgba32(0x12345678), color);
+    assert!(math::is_equal_approx(color.g, 52.0 / 255.0));
+}
+
+#[itest]
+fn color_hsv() {
+    let color = Color::from_hsv(0.25, 0.5, 0.8, 0.3);
+    assert!(color.is_equal_approx(Color::new(0.6, 0.8, 0.4, 0.3)));
+    assert!(math::is_equal_approx(color.h(), 0.25));
+    assert!(math::is_equal_approx(color.s(), 0.5));
+    assert!(math::is_equal_approx(color.v(), 0.8));
+
+    // Hue wraps around
+    assert!(Color::from_hsv(1.25, 0.5, 0.8, 0.3).is_equal_approx(color));
+    assert!(Color::from_hsv(-0.75, 0.5, 0.8, 0.3).is_equal_approx(color));
+
+    let gray = Color::from_hsv(0.7, 0.0, 0.4, 1.0);
+    assert_eq!(gray, Color::new(0.4, 0.4, 0.4, 1.0));
+    assert_eq!(gray.h(), 0.0);
+    assert_eq!(gray.s(), 0.0);
+
+    for color in [Color::RED, Color::CORNFLOWER_BLUE, Color::DARK_MAGENTA] {
+        let roundtrip = Color::from_hsv(color.h(), color.s(), color.v(), color.a);
+        assert!(roundtrip.is_equal_approx(color), "{color:?}");
+    }
+}
+
+#[itest]
+fn color_ok_hsl() {
+    // The conversion goes through Oklab and is not exact
+    let approx = |a: Color, b: Color| {
+        [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)]
+            .iter()
+            .all(|(x, y)| (x - y).abs() < 1e-4)
+    };
+
+    // Hue of pure red in OKHSL is about 29 degrees
+    let red = Color::RED;
+    assert!((red.ok_hsl_h() - 29.23 / 360.0).abs() < 1e-3);
+    assert!((red.ok_hsl_s() - 1.0).abs() < 1e-4);
+
+    for color in [
+        Color::RED,
+        Color::CORNFLOWER_BLUE,
+        Color::DARK_MAGENTA,
+        Color::new(0.2, 0.6, 0.3, 0.5),
+    ] {
+        let roundtrip = Color::from_ok_hsl(
+            color.ok_hsl_h(),
+            color.ok_hsl_s(),
+            color.ok_hsl_l(),
+            color.a,
+        );
+        assert!(approx(roundtrip, color), "{color:?} -> {roundtrip:?}");
+    }
+
+    // Grays have no saturation; lightness extremes are black and white
+    assert!(Color::new(0.5, 0.5, 0.5, 1.0).ok_hsl_s() < 1e-4);
+    assert_eq!(Color::WHITE.ok_hsl_l(), 1.0);
+    assert_eq!(Color::BLACK.ok_hsl_l(), 0.0);
+    assert_eq!(Color::from_ok_hsl(0.3, 1.0, 1.0, 1.0), Color::WHITE);
+    assert_eq!(
+        Color::from_ok_hsl(0.3, 1.0, 0.0, 0.5),
+        Color::new(0.0, 0.0, 0.0, 0.5)
+    );
+}
+
+#[itest]
+fn color_blending() {
+    let color = Color::new(0.2, 0.4, 0.8, 0.5);
+
+    assert!(color
+        .lerp(Color::WHITE, 0.5)
+        .is_equal_approx(Color::new(0.6, 0.7, 0.9, 0.75)));
+    assert!(color
+        .lightened(0.5)
+        .is_equal_approx(Color::new(0.6, 0.7, 0.9, 0.5)));
+    assert!(color
+        .darkened(0.5)
+        .is_equal_approx(Color::new(0.1, 0.2, 0.4, 0.5)));
+    assert!(color
+        .inverted()
+        .is_equal_approx(Color::new(0.8, 0.6, 0.2, 0.5)));
+
+    // Opaque over anything replaces it; half-transparent over opaque mixes evenly
+    assert_eq!(Color::BLACK.blend(Color::WHITE), Color::WHITE);
+    let blended = Color::BLACK.blend(Color::new(1.0, 1.0, 1.0, 0.5));
+    assert!(blended.is_equal_approx(Color::new(0.5, 0.5, 0.5, 1.0)));
+    assert_eq!(
+        Color::TRANSPARENT.blend(Color::TRANSPARENT),
+        Color::new(0.0, 0.0, 0.0, 0.0)
+    );
+}
+
+#[itest]
+fn color_srgb() {
+    let color = Color::new(0.0, 0.002, 0.5, 0.25);
+    let linear = color.srgb_to_linear();
+
+    assert!(math::is_equal_approx(linear.b, 0.21404114));
+    assert_eq!(linear.a, 0.25);
+    assert!(linear.linear_to_srgb().is_equal_approx(color));
+
+    assert!(math::is_equal_approx(Color::WHITE.luminance(), 1.0));
+    assert!(math::is_equal_approx(Color::BLUE.luminance(), 0.0722));
+}
+
+#[itest]
+fn color_named() {
+    assert_eq!(Color::default(), Color::BLACK);
+    assert_eq!(Color::from_html("6495ed"), Some(Color::CORNFLOWER_BLUE));
+    assert_eq!(Color::CORNFLOWER_BLUE.to_html(true), "6495edff");
+    assert_eq!(Color::TRANSPARENT.to_rgba32(), 0xffffff00);
+    assert_eq!(Color::WEB_GREEN.to_html(false), "008000");
+    assert_eq!(Color::GREEN.to_html(false), "00ff00");
+}
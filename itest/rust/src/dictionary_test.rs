@@ -0,0 +1,175 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::itest;
+use godot::builtin::{Dictionary, FromVariant, GodotString, ToVariant, Variant};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= dictionary_default();
+    ok &= dictionary_insert_get_remove();
+    ok &= dictionary_keys_values();
+    ok &= dictionary_clone_shares();
+    ok &= dictionary_duplicate();
+    ok &= dictionary_merge();
+    ok &= dictionary_iter();
+    ok &= dictionary_from_iterator();
+    ok &= dictionary_hash_map();
+    ok &= dictionary_variant();
+    ok
+}
+
+#[itest]
+fn dictionary_default() {
+    let dictionary = Dictionary::new();
+
+    assert!(dictionary.is_empty());
+    assert_eq!(dictionary.len(), 0);
+    assert_eq!(dictionary, Dictionary::default());
+}
+
+#[itest]
+fn dictionary_insert_get_remove() {
+    let mut dictionary = Dictionary::new();
+    let key = GodotString::from("key");
+
+    assert_eq!(dictionary.insert(key.clone(), 10), None);
+    assert_eq!(dictionary.insert(7, Variant::nil()), None);
+    assert_eq!(dictionary.len(), 2);
+    assert!(dictionary.contains_key(key.clone()));
+    assert!(dictionary.contains_key(7));
+    assert!(!dictionary.contains_key(8));
+
+    assert_eq!(dictionary.get(key.clone()), Some(Variant::from(10)));
+    assert_eq!(dictionary.get(7), Some(Variant::nil()));
+    assert_eq!(dictionary.get(8), None);
+    assert_eq!(dictionary.get_or_nil(8), Variant::nil());
+
+    assert_eq!(dictionary.insert(key.clone(), 20), Some(Variant::from(10)));
+    assert_eq!(dictionary.get(key.clone()), Some(Variant::from(20)));
+
+    assert_eq!(dictionary.remove(key.clone()), Some(Variant::from(20)));
+    assert_eq!(dictionary.remove(key), None);
+    assert_eq!(dictionary.len(), 1);
+
+    dictionary.clear();
+    assert!(dictionary.is_empty());
+}
+
+#[itest]
+fn dictionary_keys_values() {
+    let dictionary: Dictionary = [(3, 30), (1, 10), (2, 20)].into_iter().collect();
+
+    // Insertion order is preserved
+    let keys = dictionary.keys();
+    assert_eq!(keys.get(0), Some(Variant::from(3)));
+    assert_eq!(keys.get(1), Some(Variant::from(1)));
+    assert_eq!(keys.get(2), Some(Variant::from(2)));
+
+    let values = dictionary.values();
+    assert_eq!(values.get(0), Some(Variant::from(30)));
+    assert_eq!(values.get(2), Some(Variant::from(20)));
+}
+
+#[itest]
+fn dictionary_clone_shares() {
+    let mut dictionary = Dictionary::new();
+    let shared = dictionary.clone();
+
+    dictionary.insert(1, 2);
+    assert_eq!(shared.get(1), Some(Variant::from(2)));
+    assert_eq!(shared, dictionary);
+}
+
+#[itest]
+fn dictionary_duplicate() {
+    let mut inner = Dictionary::new();
+    inner.insert(1, 1);
+
+    let mut outer = Dictionary::new();
+    outer.insert(0, inner.clone());
+
+    let shallow = outer.duplicate_shallow();
+    let deep = outer.duplicate_deep();
+    outer.insert(2, 2);
+    inner.insert(1, 100);
+
+    assert!(!shallow.contains_key(2));
+    assert!(!deep.contains_key(2));
+
+    let shallow_inner = Dictionary::try_from_variant(&shallow.get_or_nil(0)).unwrap();
+    let deep_inner = Dictionary::try_from_variant(&deep.get_or_nil(0)).unwrap();
+    assert_eq!(shallow_inner.get(1), Some(Variant::from(100)));
+    assert_eq!(deep_inner.get(1), Some(Variant::from(1)));
+}
+
+#[itest]
+fn dictionary_merge() {
+    let mut dictionary: Dictionary = [(1, 10), (2, 20)].into_iter().collect();
+    let other: Dictionary = [(2, 200), (3, 300)].into_iter().collect();
+
+    dictionary.merge(&other, false);
+    assert_eq!(dictionary.get(2), Some(Variant::from(20)));
+    assert_eq!(dictionary.get(3), Some(Variant::from(300)));
+
+    dictionary.merge(&other, true);
+    assert_eq!(dictionary.get(2), Some(Variant::from(200)));
+    assert_eq!(dictionary.len(), 3);
+}
+
+#[itest]
+fn dictionary_iter() {
+    let dictionary: Dictionary = [(1, 10), (2, 20), (3, 30)].into_iter().collect();
+
+    let pairs: Vec<(Variant, Variant)> = dictionary.iter().collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (Variant::from(1), Variant::from(10)),
+            (Variant::from(2), Variant::from(20)),
+            (Variant::from(3), Variant::from(30)),
+        ]
+    );
+
+    let typed: Vec<(i64, i32)> = dictionary.iter_typed().collect();
+    assert_eq!(typed, vec![(1, 10), (2, 20), (3, 30)]);
+
+    assert_eq!((&dictionary).into_iter().count(), 3);
+    assert_eq!(Dictionary::new().iter().next(), None);
+}
+
+#[itest]
+fn dictionary_from_iterator() {
+    let mut dictionary: Dictionary = [(1, 10), (2, 20)].into_iter().collect();
+    dictionary.extend([(2, 0), (3, 30)]);
+
+    assert_eq!(dictionary.len(), 3);
+    assert_eq!(dictionary.get(2), Some(Variant::from(0)));
+    assert_eq!(dictionary.get(3), Some(Variant::from(30)));
+}
+
+#[itest]
+fn dictionary_hash_map() {
+    let map: HashMap<i64, f64> = [(1, 0.5), (-4, 2.25)].into_iter().collect();
+
+    let dictionary = Dictionary::from(&map);
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get(-4), Some(Variant::from(2.25)));
+
+    let roundtrip = HashMap::<i64, f64>::try_from(&dictionary).unwrap();
+    assert_eq!(roundtrip, map);
+}
+
+#[itest]
+fn dictionary_variant() {
+    let dictionary: Dictionary = [(1, 10)].into_iter().collect();
+
+    let variant = dictionary.to_variant();
+    let roundtrip = Dictionary::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, dictionary);
+}
@@ -9,28 +9,64 @@ use godot::init::{gdextension, ExtensionLibrary};
 use godot::test::itest;
 use std::panic::UnwindSafe;
 
+mod aabb_test;
+mod array_test;
 mod base_test;
+mod basis_test;
+mod callable_test;
+mod color_test;
+mod dictionary_test;
 mod enum_test;
 mod gdscript_ffi_test;
+mod node_path_test;
 mod node_test;
 mod object_test;
+mod packed_array_test;
+mod plane_test;
+mod projection_test;
+mod quaternion_test;
+mod rect2_test;
+mod rid_test;
+mod signal_test;
 mod singleton_test;
 mod string_test;
+mod transform2d_test;
+mod transform3d_test;
+mod typed_array_test;
 mod utilities_test;
 mod variant_test;
+mod vector_test;
 mod virtual_methods_test;
 
 fn run_tests() -> bool {
     let mut ok = true;
+    ok &= aabb_test::run();
+    ok &= array_test::run();
     ok &= base_test::run();
+    ok &= basis_test::run();
+    ok &= callable_test::run();
+    ok &= color_test::run();
+    ok &= dictionary_test::run();
     ok &= gdscript_ffi_test::run();
+    ok &= node_path_test::run();
     ok &= node_test::run();
     ok &= enum_test::run();
     ok &= object_test::run();
+    ok &= packed_array_test::run();
+    ok &= plane_test::run();
+    ok &= projection_test::run();
+    ok &= quaternion_test::run();
+    ok &= rect2_test::run();
+    ok &= rid_test::run();
+    ok &= signal_test::run();
     ok &= singleton_test::run();
     ok &= string_test::run();
+    ok &= transform2d_test::run();
+    ok &= transform3d_test::run();
+    ok &= typed_array_test::run();
     ok &= utilities_test::run();
     ok &= variant_test::run();
+    ok &= vector_test::run();
     ok &= virtual_methods_test::run();
     ok
 }
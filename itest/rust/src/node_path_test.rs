@@ -0,0 +1,113 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{FromVariant, GodotString, NodePath, StringName, ToVariant};
+use std::collections::HashSet;
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= node_path_default();
+    ok &= node_path_names_subnames();
+    ok &= node_path_absolute();
+    ok &= node_path_property_path();
+    ok &= node_path_join();
+    ok &= node_path_eq_hash();
+    ok &= node_path_variant();
+    ok
+}
+
+#[itest]
+fn node_path_default() {
+    let path = NodePath::new();
+
+    assert!(path.is_empty());
+    assert!(!path.is_absolute());
+    assert_eq!(path.get_name_count(), 0);
+    assert_eq!(path.get_name(0), None);
+    assert_eq!(path, NodePath::default());
+}
+
+#[itest]
+fn node_path_names_subnames() {
+    let path = NodePath::from("Path2D/Sprite:modulate:a");
+
+    assert!(!path.is_empty());
+    assert_eq!(path.get_name_count(), 2);
+    assert_eq!(path.get_name(0), Some(StringName::from("Path2D")));
+    assert_eq!(path.get_name(1), Some(StringName::from("Sprite")));
+    assert_eq!(path.get_name(2), None);
+
+    assert_eq!(path.get_subname_count(), 2);
+    assert_eq!(path.get_subname(0), Some(StringName::from("modulate")));
+    assert_eq!(path.get_subname(1), Some(StringName::from("a")));
+    assert_eq!(path.get_subname(2), None);
+
+    assert_eq!(path.get_concatenated_names(), "Path2D/Sprite");
+    assert_eq!(path.get_concatenated_subnames(), "modulate:a");
+}
+
+#[itest]
+fn node_path_absolute() {
+    let absolute = NodePath::from("/root/Level");
+    let relative = NodePath::from("../Player");
+
+    assert!(absolute.is_absolute());
+    assert!(!relative.is_absolute());
+    assert_eq!(absolute.get_concatenated_names(), "root/Level");
+}
+
+#[itest]
+fn node_path_property_path() {
+    let path = NodePath::from("Sprite:modulate");
+
+    assert_eq!(path.as_property_path(), NodePath::from(":Sprite:modulate"));
+}
+
+#[itest]
+fn node_path_join() {
+    let base = NodePath::from("/root/Level");
+
+    assert_eq!(
+        base.join(&NodePath::from("Player/Sprite:modulate")),
+        NodePath::from("/root/Level/Player/Sprite:modulate")
+    );
+    assert_eq!(
+        base.join(&NodePath::from("/other")),
+        NodePath::from("/other")
+    );
+
+    let path = NodePath::from("Player")
+        .with_name("Sprite")
+        .with_subname("modulate");
+    assert_eq!(path, NodePath::from("Player/Sprite:modulate"));
+    assert_eq!(path.to_string(), "Player/Sprite:modulate");
+}
+
+#[itest]
+fn node_path_eq_hash() {
+    let set: HashSet<NodePath> = ["a/b", "a/b", "a/b:c"]
+        .into_iter()
+        .map(NodePath::from)
+        .collect();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&NodePath::from("a/b:c")));
+    assert_ne!(NodePath::from("a"), NodePath::from("/a"));
+}
+
+#[itest]
+fn node_path_variant() {
+    let path = NodePath::from("Parent/Child:position:x");
+
+    let variant = path.to_variant();
+    let roundtrip = NodePath::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, path);
+    assert_eq!(
+        GodotString::from(&roundtrip),
+        GodotString::from("Parent/Child:position:x")
+    );
+}
@@ -6,7 +6,10 @@
 
 use crate::{expect_panic, itest};
 use godot::bind::{godot_api, GodotClass, GodotExt};
-use godot::builtin::{FromVariant, GodotString, StringName, ToVariant, Variant, Vector3};
+use godot::builtin::{
+    FromVariant, GodotString, StringName, ToVariant, Variant, VariantConversionReason, VariantType,
+    Vector3,
+};
 use godot::engine::{Node, Node3D, Object, RefCounted};
 use godot::obj::Share;
 use godot::obj::{Base, Gd, InstanceId};
@@ -36,6 +39,7 @@ pub fn run() -> bool {
     ok &= object_from_instance_id_unrelated_type();
     ok &= object_user_convert_variant();
     ok &= object_engine_convert_variant();
+    ok &= object_convert_variant_errors();
     ok &= object_engine_up_deref();
     ok &= object_engine_up_deref_mut();
     ok &= object_engine_upcast();
@@ -225,6 +229,34 @@ fn object_engine_convert_variant() {
     obj.free();
 }
 
+#[itest]
+fn object_convert_variant_errors() {
+    let err = Gd::<Node>::try_from_variant(&Variant::nil()).unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::NullObject);
+
+    let err = Gd::<Node>::try_from_variant(&17.to_variant()).unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::BadType);
+    assert_eq!(err.actual(), VariantType::Int);
+
+    let refc = RefCounted::new();
+    let err = Gd::<Node>::try_from_variant(&refc.to_variant()).unwrap_err();
+    assert_eq!(
+        err.reason(),
+        VariantConversionReason::WrongClass {
+            expected_class: "Node"
+        }
+    );
+
+    let node3d = Node3D::new_alloc();
+    let variant = node3d.share().to_variant();
+    let node = Gd::<Node>::try_from_variant(&variant).expect("upcast conversion");
+    assert_eq!(node.instance_id(), node3d.instance_id());
+
+    node3d.free();
+    let err = Gd::<Node>::try_from_variant(&variant).unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::NullObject);
+}
+
 #[itest]
 fn object_engine_up_deref() {
     let node3d: Gd<Node3D> = Node3D::new_alloc();
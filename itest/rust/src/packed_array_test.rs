@@ -0,0 +1,121 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{
+    Array, ByteArray, Color, ColorArray, Float32Array, FromVariant, GodotString, Int64Array,
+    StringArray, ToVariant, Variant, Vector2, Vector2Array,
+};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= packed_array_default();
+    ok &= packed_array_from_slice();
+    ok &= packed_array_push_resize();
+    ok &= packed_array_mut_slice();
+    ok &= packed_array_copy_on_write();
+    ok &= packed_array_iter_extend();
+    ok &= packed_array_strings();
+    ok &= packed_array_conversions();
+    ok
+}
+
+#[itest]
+fn packed_array_default() {
+    let array = ByteArray::new();
+
+    assert!(array.is_empty());
+    assert_eq!(array.len(), 0);
+    assert!(array.as_slice().is_empty());
+    assert_eq!(array, ByteArray::default());
+}
+
+#[itest]
+fn packed_array_from_slice() {
+    let values = [Vector2::new(1.0, 2.0), Vector2::new(-3.5, 0.0)];
+    let array = Vector2Array::from(&values[..]);
+
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.as_slice(), &values);
+    assert_eq!(array.get(1), Some(Vector2::new(-3.5, 0.0)));
+    assert_eq!(array.get(2), None);
+}
+
+#[itest]
+fn packed_array_push_resize() {
+    let mut array = Int64Array::new();
+    array.push(7);
+    array.push(-8);
+    assert_eq!(array.as_slice(), &[7, -8]);
+
+    array.resize(4);
+    assert_eq!(array.as_slice(), &[7, -8, 0, 0]);
+
+    array.resize(1);
+    assert_eq!(array.as_slice(), &[7]);
+}
+
+#[itest]
+fn packed_array_mut_slice() {
+    let mut array = Float32Array::from(&[0.0, 0.5, 1.0][..]);
+
+    for value in array.as_mut_slice() {
+        *value *= 2.0;
+    }
+    array.set(0, -1.0);
+
+    assert_eq!(array.as_slice(), &[-1.0, 1.0, 2.0]);
+}
+
+#[itest]
+fn packed_array_copy_on_write() {
+    let mut array = ColorArray::from(&[Color::RED, Color::GREEN][..]);
+    let copy = array.clone();
+
+    array.as_mut_slice()[0] = Color::BLUE;
+    array.push(Color::WHITE);
+
+    assert_eq!(copy.as_slice(), &[Color::RED, Color::GREEN]);
+    assert_eq!(array.as_slice(), &[Color::BLUE, Color::GREEN, Color::WHITE]);
+}
+
+#[itest]
+fn packed_array_iter_extend() {
+    let mut array: ByteArray = (1..=3).collect();
+    array.extend([4, 5]);
+    array.extend_from_slice(&[6]);
+
+    let sum: u32 = array.iter().map(|&byte| u32::from(byte)).sum();
+    assert_eq!(sum, 21);
+
+    let doubled: Vec<u8> = (&array).into_iter().map(|byte| byte * 2).collect();
+    assert_eq!(doubled, vec![2, 4, 6, 8, 10, 12]);
+}
+
+#[itest]
+fn packed_array_strings() {
+    let mut array = StringArray::new();
+    array.push(GodotString::from("hello"));
+    array.push(GodotString::from("world"));
+    array.set(0, GodotString::from("goodbye"));
+
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.get(0), Some(GodotString::from("goodbye")));
+    assert_eq!(array.as_slice()[1], GodotString::from("world"));
+}
+
+#[itest]
+fn packed_array_conversions() {
+    let array = Int64Array::from(&[1, 2, 3][..]);
+
+    let variant = array.to_variant();
+    let roundtrip = Int64Array::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, array);
+
+    let untyped = Array::from(&array);
+    assert_eq!(untyped.get(2), Some(Variant::from(3)));
+    assert_eq!(Int64Array::from(&untyped), array);
+}
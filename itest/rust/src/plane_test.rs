@@ -0,0 +1,95 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Plane, Vector3};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= plane_construction();
+    ok &= plane_distance_and_projection();
+    ok &= plane_intersect_3();
+    ok &= plane_intersects_ray();
+    ok &= plane_intersects_segment();
+    ok
+}
+
+#[itest]
+fn plane_construction() {
+    let plane = Plane::from_points(
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector3::new(1.0, 2.0, 0.0),
+        Vector3::new(0.0, 2.0, 1.0),
+    );
+    assert!(plane.is_equal_approx(Plane::new(Vector3::UP, 2.0)));
+
+    let plane = Plane::from_normal_point(Vector3::RIGHT, Vector3::new(3.0, 7.0, -1.0));
+    assert_eq!(plane, Plane::new(Vector3::RIGHT, 3.0));
+    assert_eq!(plane.center(), Vector3::new(3.0, 0.0, 0.0));
+    assert_eq!(-plane, Plane::new(Vector3::LEFT, -3.0));
+
+    let unnormalized = Plane::new(Vector3::new(0.0, 0.0, 2.0), 4.0);
+    assert_eq!(unnormalized.normalized(), Plane::new(Vector3::BACK, 2.0));
+}
+
+#[itest]
+fn plane_distance_and_projection() {
+    let plane = Plane::new(Vector3::UP, 1.0);
+    let point = Vector3::new(5.0, 4.0, -2.0);
+
+    assert_eq!(plane.distance_to(point), 3.0);
+    assert_eq!(plane.distance_to(Vector3::ZERO), -1.0);
+    assert!(plane.is_point_over(point));
+    assert!(!plane.is_point_over(Vector3::ZERO));
+    assert!(plane.has_point(Vector3::new(9.0, 1.0, 9.0), 0.0));
+    assert!(!plane.has_point(point, 2.9));
+    assert_eq!(plane.project(point), Vector3::new(5.0, 1.0, -2.0));
+}
+
+#[itest]
+fn plane_intersect_3() {
+    let x = Plane::new(Vector3::RIGHT, 1.0);
+    let y = Plane::new(Vector3::UP, 2.0);
+    let z = Plane::new(Vector3::BACK, 3.0);
+
+    let point = x.intersect_3(y, z).expect("planes should intersect");
+    assert!(point.is_equal_approx(Vector3::new(1.0, 2.0, 3.0)));
+
+    let parallel = Plane::new(Vector3::RIGHT, 5.0);
+    assert_eq!(x.intersect_3(parallel, z), None);
+}
+
+#[itest]
+fn plane_intersects_ray() {
+    let plane = Plane::new(Vector3::UP, 0.0);
+
+    assert_eq!(
+        plane.intersects_ray(Vector3::new(1.0, 5.0, 2.0), Vector3::DOWN),
+        Some(Vector3::new(1.0, 0.0, 2.0))
+    );
+    assert_eq!(
+        plane.intersects_ray(Vector3::new(1.0, 5.0, 2.0), Vector3::UP),
+        None
+    );
+    assert_eq!(
+        plane.intersects_ray(Vector3::new(1.0, 5.0, 2.0), Vector3::RIGHT),
+        None
+    );
+}
+
+#[itest]
+fn plane_intersects_segment() {
+    let plane = Plane::new(Vector3::UP, 0.0);
+
+    assert_eq!(
+        plane.intersects_segment(Vector3::new(0.0, 2.0, 0.0), Vector3::new(4.0, -2.0, 0.0)),
+        Some(Vector3::new(2.0, 0.0, 0.0))
+    );
+    assert_eq!(
+        plane.intersects_segment(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 0.0)),
+        None
+    );
+}
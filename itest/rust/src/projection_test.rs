@@ -0,0 +1,157 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{math, Basis, Projection, Transform3D, Vector2, Vector3, Vector4};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= projection_perspective();
+    ok &= projection_orthogonal();
+    ok &= projection_frustum();
+    ok &= projection_planes();
+    ok &= projection_inverse_and_mul();
+    ok &= projection_from_transform3d();
+    ok
+}
+
+/// Applies the projection and performs the perspective divide.
+fn project(projection: Projection, point: Vector3) -> Vector3 {
+    let clip = projection * Vector4::new(point.x(), point.y(), point.z(), 1.0);
+    Vector3::new(clip.x(), clip.y(), clip.z()) / clip.w()
+}
+
+#[itest]
+fn projection_perspective() {
+    let projection = Projection::create_perspective(90.0, 2.0, 1.0, 100.0, false);
+
+    assert!(!projection.is_orthogonal());
+    assert!(math::is_equal_approx(projection.get_z_near(), 1.0));
+    assert!(math::is_equal_approx(projection.get_z_far(), 100.0));
+
+    let near = project(projection, Vector3::new(0.0, 1.0, -1.0));
+    assert!(near.is_equal_approx(Vector3::new(0.0, 1.0, -1.0)));
+
+    let far = project(projection, Vector3::new(200.0, 0.0, -100.0));
+    assert!(far.is_equal_approx(Vector3::new(1.0, 0.0, 1.0)));
+
+    // Horizontal FOV of 90 degrees with aspect 2 is a vertical FOV of ~53.13 degrees
+    let flipped = Projection::create_perspective(90.0, 2.0, 1.0, 100.0, true);
+    let expected = Projection::create_perspective(53.130104, 2.0, 1.0, 100.0, false);
+    assert!(flipped.is_equal_approx(expected));
+}
+
+#[itest]
+fn projection_orthogonal() {
+    let projection = Projection::create_orthogonal(-4.0, 4.0, -2.0, 2.0, 0.5, 10.5);
+
+    assert!(projection.is_orthogonal());
+    assert!(math::is_equal_approx(projection.get_z_near(), 0.5));
+    assert!(math::is_equal_approx(projection.get_z_far(), 10.5));
+
+    let corner = project(projection, Vector3::new(4.0, -2.0, -0.5));
+    assert!(corner.is_equal_approx(Vector3::new(1.0, -1.0, -1.0)));
+
+    let aspect = Projection::create_orthogonal_aspect(8.0, 2.0, 0.5, 10.5, true);
+    assert!(aspect.is_equal_approx(projection));
+
+    let aspect = Projection::create_orthogonal_aspect(4.0, 2.0, 0.5, 10.5, false);
+    assert!(aspect.is_equal_approx(projection));
+}
+
+#[itest]
+fn projection_frustum() {
+    let frustum = Projection::create_frustum(-2.0, 2.0, -1.0, 1.0, 1.0, 50.0);
+    let perspective = Projection::create_perspective(90.0, 2.0, 1.0, 50.0, false);
+    assert!(frustum.is_equal_approx(perspective));
+
+    let aspect = Projection::create_frustum_aspect(2.0, 2.0, Vector2::ZERO, 1.0, 50.0, false);
+    assert!(aspect.is_equal_approx(frustum));
+
+    // Off-center frustum: the shifted near plane's corner maps to the NDC corner
+    let shifted =
+        Projection::create_frustum_aspect(2.0, 2.0, Vector2::new(1.0, 0.5), 1.0, 50.0, false);
+    let corner = project(shifted, Vector3::new(3.0, 1.5, -1.0));
+    assert!(corner.is_equal_approx(Vector3::new(1.0, 1.0, -1.0)));
+}
+
+#[itest]
+fn projection_planes() {
+    let projection = Projection::create_perspective(90.0, 1.0, 1.0, 100.0, false);
+    let [near, far, left, top, right, bottom] =
+        projection.get_projection_planes(Transform3D::IDENTITY);
+
+    assert!(near.normal.is_equal_approx(Vector3::BACK));
+    assert!(math::is_equal_approx(near.d, -1.0));
+    assert!(far.normal.is_equal_approx(Vector3::FORWARD));
+    assert!(math::is_equal_approx(far.d, 100.0));
+
+    let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+    assert!(left
+        .normal
+        .is_equal_approx(Vector3::new(-diagonal, 0.0, diagonal)));
+    assert!(top
+        .normal
+        .is_equal_approx(Vector3::new(0.0, diagonal, diagonal)));
+    assert!(right
+        .normal
+        .is_equal_approx(Vector3::new(diagonal, 0.0, diagonal)));
+    assert!(bottom
+        .normal
+        .is_equal_approx(Vector3::new(0.0, -diagonal, diagonal)));
+
+    // Camera moved back and rotated to look along +X: frustum follows
+    let camera = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, -std::f32::consts::FRAC_PI_2),
+        Vector3::new(-10.0, 0.0, 0.0),
+    );
+    let planes = projection.get_projection_planes(camera);
+
+    let inside = Vector3::new(10.0, 0.0, 0.0);
+    let outside = Vector3::new(-20.0, 0.0, 0.0);
+    assert!(planes.iter().all(|plane| !plane.is_point_over(inside)));
+    assert!(planes.iter().any(|plane| plane.is_point_over(outside)));
+}
+
+#[itest]
+fn projection_inverse_and_mul() {
+    let projection = Projection::create_perspective(60.0, 1.5, 0.1, 20.0, false);
+
+    assert!((projection * projection.inverse()).is_equal_approx(Projection::IDENTITY));
+    assert_eq!(Projection::default(), Projection::IDENTITY);
+    assert_eq!(Projection::IDENTITY * projection, projection);
+
+    let mut accumulated = projection;
+    accumulated *= Projection::IDENTITY;
+    assert_eq!(accumulated, projection);
+
+    let v = Vector4::new(1.0, -2.0, 3.0, 1.0);
+    assert!(((projection * projection) * v).is_equal_approx(projection * (projection * v)));
+    assert!(math::is_equal_approx(
+        Projection::IDENTITY.determinant(),
+        1.0
+    ));
+}
+
+#[itest]
+fn projection_from_transform3d() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.3)
+            .scaled(Vector3::new(2.0, 1.0, 0.5)),
+        Vector3::new(3.0, -1.0, 7.5),
+    );
+    let projection = Projection::from_transform3d(transform);
+
+    let point = Vector3::new(-2.0, 0.5, 4.0);
+    let projected = projection * Vector4::new(point.x(), point.y(), point.z(), 1.0);
+    let transformed = transform * point;
+    assert!(projected.is_equal_approx(Vector4::new(
+        transformed.x(),
+        transformed.y(),
+        transformed.z(),
+        1.0
+    )));
+}
@@ -0,0 +1,80 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Quaternion, Vector3};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= quaternion_axis_angle();
+    ok &= quaternion_rotate_vector();
+    ok &= quaternion_euler_roundtrip();
+    ok &= quaternion_inverse_and_compose();
+    ok &= quaternion_slerp();
+    ok
+}
+
+#[itest]
+fn quaternion_axis_angle() {
+    let quat = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+    assert!(quat.is_normalized());
+    assert!(quat.get_axis().is_equal_approx(Vector3::UP));
+    assert!(is_equal_approx(quat.get_angle(), FRAC_PI_2));
+    assert_eq!(Quaternion::default(), Quaternion::IDENTITY);
+    assert_eq!(Quaternion::IDENTITY.w(), 1.0);
+}
+
+#[itest]
+fn quaternion_rotate_vector() {
+    let quat = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+    assert!((quat * Vector3::RIGHT).is_equal_approx(Vector3::FORWARD));
+    assert!((quat * Vector3::UP).is_equal_approx(Vector3::UP));
+}
+
+#[itest]
+fn quaternion_euler_roundtrip() {
+    let euler = Vector3::new(0.3, -1.2, 0.7);
+    let quat = Quaternion::from_euler(euler);
+
+    assert!(quat.get_euler().is_equal_approx(euler));
+
+    // Y (yaw) is applied last, so it rotates the result of X and Z
+    let yaw = Quaternion::from_euler(Vector3::new(0.0, FRAC_PI_2, 0.0));
+    assert!((yaw * Vector3::RIGHT).is_equal_approx(Vector3::FORWARD));
+}
+
+#[itest]
+fn quaternion_inverse_and_compose() {
+    let a = Quaternion::from_axis_angle(Vector3::RIGHT, 0.4);
+    let b = Quaternion::from_axis_angle(Vector3::UP, -1.1);
+
+    assert!((a * a.inverse()).is_equal_approx(Quaternion::IDENTITY));
+
+    let vec = Vector3::new(1.0, 2.0, 3.0);
+    assert!(((a * b) * vec).is_equal_approx(a * (b * vec)));
+    assert!(is_equal_approx(a.angle_to(a), 0.0));
+    assert!(is_equal_approx(
+        Quaternion::IDENTITY.angle_to(Quaternion::from_axis_angle(Vector3::UP, 1.1)),
+        1.1
+    ));
+}
+
+#[itest]
+fn quaternion_slerp() {
+    let from = Quaternion::IDENTITY;
+    let to = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+    let halfway = from.slerp(to, 0.5);
+    assert!(halfway.is_equal_approx(Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_4)));
+    assert!(from.slerp(to, 1.0).is_equal_approx(to));
+}
+
+fn is_equal_approx(a: f32, b: f32) -> bool {
+    godot::builtin::math::is_equal_approx(a, b)
+}
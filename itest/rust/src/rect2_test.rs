@@ -0,0 +1,144 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Rect2, Rect2i, Vector2, Vector2i};
+use godot::engine::global::Side;
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= rect2_basics();
+    ok &= rect2_has_point();
+    ok &= rect2_intersection();
+    ok &= rect2_merge_and_encloses();
+    ok &= rect2_grow();
+    ok &= rect2_abs();
+    ok &= rect2i_conversions();
+    ok &= rect2i_intersection();
+    ok
+}
+
+#[itest]
+fn rect2_basics() {
+    let mut rect = Rect2::from_components(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(rect.position, Vector2::new(1.0, 2.0));
+    assert_eq!(rect.size, Vector2::new(3.0, 4.0));
+    assert_eq!(rect.end(), Vector2::new(4.0, 6.0));
+    assert_eq!(rect.center(), Vector2::new(2.5, 4.0));
+    assert_eq!(rect.area(), 12.0);
+    assert!(rect.has_area());
+
+    rect.set_end(Vector2::new(2.0, 2.0));
+    assert_eq!(rect.size, Vector2::new(1.0, 0.0));
+    assert!(!rect.has_area());
+}
+
+#[itest]
+fn rect2_has_point() {
+    let rect = Rect2::from_components(0.0, 0.0, 2.0, 2.0);
+
+    assert!(rect.has_point(Vector2::ZERO));
+    assert!(rect.has_point(Vector2::new(1.0, 1.999)));
+    assert!(!rect.has_point(Vector2::new(2.0, 1.0)));
+    assert!(!rect.has_point(Vector2::new(-0.1, 1.0)));
+}
+
+#[itest]
+fn rect2_intersection() {
+    let a = Rect2::from_components(0.0, 0.0, 4.0, 4.0);
+    let b = Rect2::from_components(2.0, 3.0, 4.0, 4.0);
+    let touching = Rect2::from_components(4.0, 0.0, 1.0, 1.0);
+
+    assert!(a.intersects(b, false));
+    assert!(!a.intersects(touching, false));
+    assert!(a.intersects(touching, true));
+
+    assert_eq!(
+        a.intersection(b),
+        Some(Rect2::from_components(2.0, 3.0, 2.0, 1.0))
+    );
+    assert_eq!(a.intersection(touching), None);
+}
+
+#[itest]
+fn rect2_merge_and_encloses() {
+    let a = Rect2::from_components(0.0, 0.0, 1.0, 1.0);
+    let b = Rect2::from_components(2.0, -1.0, 1.0, 1.0);
+    let merged = a.merge(b);
+
+    assert_eq!(merged, Rect2::from_components(0.0, -1.0, 3.0, 2.0));
+    assert!(merged.encloses(a));
+    assert!(merged.encloses(b));
+    assert!(!a.encloses(merged));
+
+    assert_eq!(
+        a.expand(Vector2::new(-1.0, 3.0)),
+        Rect2::from_components(-1.0, 0.0, 2.0, 3.0)
+    );
+}
+
+#[itest]
+fn rect2_grow() {
+    let rect = Rect2::from_components(1.0, 1.0, 2.0, 2.0);
+
+    assert_eq!(rect.grow(1.0), Rect2::from_components(0.0, 0.0, 4.0, 4.0));
+    assert_eq!(
+        rect.grow_side(Side::SIDE_LEFT, 1.0),
+        Rect2::from_components(0.0, 1.0, 3.0, 2.0)
+    );
+    assert_eq!(
+        rect.grow_side(Side::SIDE_BOTTOM, 0.5),
+        Rect2::from_components(1.0, 1.0, 2.0, 2.5)
+    );
+    assert_eq!(
+        rect.grow_individual(1.0, 2.0, 3.0, 4.0),
+        Rect2::from_components(0.0, -1.0, 6.0, 8.0)
+    );
+}
+
+#[itest]
+fn rect2_abs() {
+    let rect = Rect2::from_components(3.0, 3.0, -2.0, 1.0);
+
+    assert_eq!(rect.abs(), Rect2::from_components(1.0, 3.0, 2.0, 1.0));
+    assert_eq!(rect.abs().end(), Vector2::new(3.0, 4.0));
+}
+
+#[itest]
+fn rect2i_conversions() {
+    let rect = Rect2::from_components(1.9, -1.9, 3.5, 4.0);
+    let recti = Rect2i::from_rect2(rect);
+
+    assert_eq!(recti, Rect2i::from_components(1, -1, 3, 4));
+    assert_eq!(
+        recti.to_rect2(),
+        Rect2::from_components(1.0, -1.0, 3.0, 4.0)
+    );
+    assert_eq!(recti.center(), Vector2i::new(2, 1));
+    assert_eq!(recti.area(), 12);
+}
+
+#[itest]
+fn rect2i_intersection() {
+    let a = Rect2i::from_components(0, 0, 4, 4);
+    let b = Rect2i::from_components(3, -2, 4, 4);
+
+    assert!(a.intersects(b));
+    assert!(!a.intersects(Rect2i::from_components(4, 0, 1, 1)));
+    assert_eq!(a.intersection(b), Some(Rect2i::from_components(3, 0, 1, 2)));
+    assert_eq!(a.merge(b), Rect2i::from_components(0, -2, 7, 6));
+    assert!(a.has_point(Vector2i::new(3, 3)));
+    assert!(!a.has_point(Vector2i::new(4, 3)));
+    assert_eq!(
+        a.grow_side(Side::SIDE_RIGHT, 2),
+        Rect2i::from_components(0, 0, 6, 4)
+    );
+    assert_eq!(
+        Rect2i::from_components(2, 2, -2, -2).abs(),
+        Rect2i::from_components(0, 0, 2, 2)
+    );
+}
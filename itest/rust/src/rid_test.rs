@@ -0,0 +1,71 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{FromVariant, Rid, ToVariant};
+use godot::engine::RenderingServer;
+use std::collections::BTreeSet;
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= rid_invalid();
+    ok &= rid_id_ordering();
+    ok &= rid_variant();
+    ok &= rid_server_methods();
+    ok
+}
+
+#[itest]
+fn rid_invalid() {
+    let rid = Rid::default();
+
+    assert!(!rid.is_valid());
+    assert_eq!(rid, Rid::INVALID);
+    assert_eq!(rid.get_id(), 0);
+    assert_eq!(Rid::new(0), Rid::INVALID);
+}
+
+#[itest]
+fn rid_id_ordering() {
+    let low = Rid::new(5);
+    let high = Rid::new(u64::MAX);
+
+    assert!(low.is_valid());
+    assert_eq!(low.get_id(), 5);
+    assert_eq!(high.get_id(), u64::MAX);
+    assert!(Rid::INVALID < low);
+    assert!(low < high);
+
+    let set: BTreeSet<Rid> = [high, low, Rid::new(5)].into_iter().collect();
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![low, high]);
+}
+
+#[itest]
+fn rid_variant() {
+    let rid = Rid::new(42);
+
+    let variant = rid.to_variant();
+    assert_eq!(Rid::try_from_variant(&variant).unwrap(), rid);
+
+    let variant = Rid::INVALID.to_variant();
+    assert_eq!(Rid::try_from_variant(&variant).unwrap(), Rid::INVALID);
+}
+
+#[itest]
+fn rid_server_methods() {
+    let mut server = RenderingServer::singleton();
+
+    let canvas = server.canvas_create().expect("server returns valid RID");
+    let item = server
+        .canvas_item_create()
+        .expect("server returns valid RID");
+    assert!(canvas.is_valid());
+    assert_ne!(canvas, item);
+
+    server.canvas_item_set_parent(item, canvas);
+    server.free_rid(item);
+    server.free_rid(canvas);
+}
@@ -0,0 +1,92 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Callable, FromVariant, Signal, StringName, ToVariant, Vector3};
+use godot::engine::object::ConnectFlags;
+use godot::engine::{global, Node3D};
+use godot::obj::Share;
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= signal_default();
+    ok &= signal_target();
+    ok &= signal_connect_emit();
+    ok &= signal_variant();
+    ok
+}
+
+#[itest]
+fn signal_default() {
+    let signal = Signal::default();
+
+    assert!(signal.is_null());
+    assert_eq!(signal.get_object_id(), None);
+    assert!(signal.get_object().is_none());
+}
+
+#[itest]
+fn signal_target() {
+    let node = Node3D::new_alloc();
+    let signal = Signal::new(node.share(), "renamed");
+
+    assert!(!signal.is_null());
+    assert_eq!(signal.get_name(), StringName::from("renamed"));
+    assert_eq!(signal.get_object_id(), Some(node.instance_id()));
+    assert_eq!(signal, Signal::new(node.share(), "renamed"));
+    assert_ne!(signal, Signal::new(node.share(), "ready"));
+
+    node.free();
+}
+
+#[itest]
+fn signal_connect_emit() {
+    let source = Node3D::new_alloc();
+    let target = Node3D::new_alloc();
+    let pos = Vector3::new(1.0, 2.0, 3.0);
+
+    let signal = Signal::new(source.share(), "renamed");
+    let callable =
+        Callable::from_object_method(target.share(), "set_position").bind(&[pos.to_variant()]);
+
+    assert_eq!(signal.connect(&callable, None), global::Error::OK);
+    assert!(signal.is_connected(&callable));
+    assert_eq!(signal.get_connections().len(), 1);
+
+    signal.emit(&[]);
+    assert_eq!(target.get_position(), pos);
+
+    signal.disconnect(&callable);
+    assert!(!signal.is_connected(&callable));
+    assert!(signal.get_connections().is_empty());
+
+    // Reference-counted connections can be made repeatedly, and need as many disconnects
+    let flags = Some(ConnectFlags::REFERENCE_COUNTED);
+    assert_eq!(signal.connect(&callable, flags), global::Error::OK);
+    assert_eq!(signal.connect(&callable, flags), global::Error::OK);
+    assert_eq!(signal.get_connections().len(), 1);
+
+    signal.disconnect(&callable);
+    assert!(signal.is_connected(&callable));
+    signal.disconnect(&callable);
+    assert!(!signal.is_connected(&callable));
+
+    source.free();
+    target.free();
+}
+
+#[itest]
+fn signal_variant() {
+    let node = Node3D::new_alloc();
+    let signal = Signal::new(node.share(), "ready");
+
+    let variant = signal.to_variant();
+    let roundtrip = Signal::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, signal);
+    assert_eq!(roundtrip.get_name(), StringName::from("ready"));
+
+    node.free();
+}
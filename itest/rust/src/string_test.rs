@@ -5,7 +5,12 @@
  */
 
 use crate::itest;
-use godot::builtin::{GodotString, StringName};
+use godot::builtin::{
+    static_name, Dictionary, FromVariant, GodotString, StringArray, StringName, ToVariant,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -15,8 +20,18 @@ pub fn run() -> bool {
     ok &= string_equality();
     ok &= string_ordering();
     ok &= string_clone();
+    ok &= string_len_chars();
+    ok &= string_utf16();
+    ok &= string_search();
+    ok &= string_split_substr();
+    ok &= string_case();
+    ok &= string_format();
+    ok &= string_concat_hash();
     ok &= string_name_conversion();
     ok &= string_name_default_construct();
+    ok &= string_name_interned_eq_hash();
+    ok &= string_name_static();
+    ok &= string_name_variant();
     ok
 }
 
@@ -58,6 +73,93 @@ fn string_clone() {
     assert_eq!(first, cloned);
 }
 
+#[itest]
+fn string_len_chars() {
+    let string = GodotString::from("héllo ∑");
+
+    assert_eq!(string.len(), 7);
+    assert!(!string.is_empty());
+    assert_eq!(string.chars(), &['h', 'é', 'l', 'l', 'o', ' ', '∑']);
+
+    let empty = GodotString::new();
+    assert!(empty.is_empty());
+    assert!(empty.chars().is_empty());
+}
+
+#[itest]
+fn string_utf16() {
+    let text = "grüße 🎮";
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+
+    let string = GodotString::from_utf16(&utf16);
+    assert_eq!(string, GodotString::from(text));
+    assert_eq!(string.to_utf16(), utf16);
+}
+
+#[itest]
+fn string_search() {
+    let string = GodotString::from("one two one");
+
+    assert_eq!(string.find("two"), Some(4));
+    assert_eq!(string.find("one"), Some(0));
+    assert_eq!(string.find("three"), None);
+    assert!(string.contains("o o"));
+    assert!(string.begins_with("one "));
+    assert!(string.ends_with(&GodotString::from(" one")));
+    assert!(!string.ends_with("two"));
+}
+
+#[itest]
+fn string_split_substr() {
+    let string = GodotString::from("a,b,,c");
+
+    let expected = ["a", "b", "", "c"].map(GodotString::from);
+    assert_eq!(string.split(","), StringArray::from(&expected[..]));
+
+    assert_eq!(string.substr(2..3), GodotString::from("b"));
+    assert_eq!(string.substr(4..100), GodotString::from(",c"));
+    assert_eq!(string.substr(3..3), GodotString::new());
+}
+
+#[itest]
+fn string_case() {
+    let string = GodotString::from("Mixed Case");
+
+    assert_eq!(string.to_lower(), GodotString::from("mixed case"));
+    assert_eq!(string.to_upper(), GodotString::from("MIXED CASE"));
+}
+
+#[itest]
+fn string_format() {
+    let mut values = Dictionary::new();
+    values.insert(GodotString::from("name"), GodotString::from("Godot"));
+    values.insert(GodotString::from("version"), 4);
+
+    let template = GodotString::from("{name} {version}!");
+    assert_eq!(template.format(&values), GodotString::from("Godot 4!"));
+}
+
+#[itest]
+fn string_concat_hash() {
+    let mut string = GodotString::from("foo") + &GodotString::from("bar");
+    assert_eq!(string, GodotString::from("foobar"));
+
+    string += &GodotString::from("∑");
+    assert_eq!(string, GodotString::from("foobar∑"));
+
+    // Hash is computed by Godot, and consistent with equality
+    let hash = |s: &GodotString| {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&string), hash(&GodotString::from("foobar∑")));
+
+    let set: HashSet<GodotString> = ["a", "b", "a"].into_iter().map(GodotString::from).collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&GodotString::from("b")));
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[itest]
@@ -76,3 +178,44 @@ fn string_name_default_construct() {
 
     assert_eq!(back, GodotString::new());
 }
+
+#[itest]
+fn string_name_interned_eq_hash() {
+    let first = StringName::from("some name");
+    let second = StringName::from(GodotString::from("some name"));
+    let different = StringName::from("other name");
+
+    assert_eq!(first, second);
+    assert_ne!(first, different);
+    assert_eq!(first.cmp(&second), std::cmp::Ordering::Equal);
+    assert_eq!(first, "some name");
+    assert_ne!(first, "other name");
+
+    let set: HashSet<StringName> = [first.clone(), second, different].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&first));
+}
+
+#[itest]
+fn string_name_static() {
+    fn ready() -> StringName {
+        static_name!("ready")
+    }
+
+    assert_eq!(ready(), ready());
+    assert_eq!(ready(), StringName::from("ready"));
+    assert_eq!(ready(), "ready");
+}
+
+#[itest]
+fn string_name_variant() {
+    let name = StringName::from("variant name");
+
+    let variant = name.to_variant();
+    let roundtrip = StringName::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, name);
+
+    let string = GodotString::from(name);
+    let variant = string.to_variant();
+    assert_eq!(GodotString::try_from_variant(&variant).unwrap(), string);
+}
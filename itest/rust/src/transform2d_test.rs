@@ -0,0 +1,180 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Rect2, Transform2D, Vector2};
+use godot::engine::Node2D;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= transform2d_xform();
+    ok &= transform2d_composition();
+    ok &= transform2d_inverse();
+    ok &= transform2d_decomposition();
+    ok &= transform2d_global_and_local();
+    ok &= transform2d_interpolate();
+    ok &= transform2d_xform_rect();
+    ok &= transform2d_matches_node2d();
+    ok
+}
+
+#[itest]
+fn transform2d_xform() {
+    let transform = Transform2D::from_angle_origin(FRAC_PI_2, Vector2::new(10.0, 0.0));
+
+    let point = transform * Vector2::RIGHT;
+    assert!(point.is_equal_approx(Vector2::new(10.0, 1.0)));
+    assert!(transform.xform_inv(point).is_equal_approx(Vector2::RIGHT));
+    assert!(transform
+        .basis_xform(Vector2::RIGHT)
+        .is_equal_approx(Vector2::DOWN));
+    assert_eq!(Transform2D::default(), Transform2D::IDENTITY);
+    assert_eq!(
+        Transform2D::FLIP_X * Vector2::new(3.0, 4.0),
+        Vector2::new(-3.0, 4.0)
+    );
+}
+
+#[itest]
+fn transform2d_composition() {
+    let rotate = Transform2D::from_angle_origin(FRAC_PI_2, Vector2::ZERO);
+    let translate = Transform2D::IDENTITY.translated(Vector2::new(5.0, 0.0));
+
+    // Right-hand side is applied first
+    let combined = rotate * translate;
+    assert!((combined * Vector2::ZERO).is_equal_approx(Vector2::new(0.0, 5.0)));
+
+    let mut accumulated = translate;
+    accumulated *= rotate;
+    assert!((accumulated * Vector2::ZERO).is_equal_approx(Vector2::new(5.0, 0.0)));
+}
+
+#[itest]
+fn transform2d_inverse() {
+    let transform = Transform2D::from_angle_scale_skew_origin(
+        0.7,
+        Vector2::new(2.0, -3.0),
+        0.2,
+        Vector2::new(1.0, 2.0),
+    );
+    assert!((transform * transform.affine_inverse()).is_equal_approx(Transform2D::IDENTITY));
+    assert!((transform.affine_inverse() * transform).is_equal_approx(Transform2D::IDENTITY));
+
+    let rigid = Transform2D::from_angle_origin(1.3, Vector2::new(-4.0, 9.0));
+    assert!(rigid.inverse().is_equal_approx(rigid.affine_inverse()));
+
+    // Singular basis (zero scale) does not panic
+    let singular = Transform2D::from_angle_scale_skew_origin(0.0, Vector2::ZERO, 0.0, Vector2::ONE);
+    assert!(!singular.affine_inverse().is_finite());
+}
+
+#[itest]
+fn transform2d_decomposition() {
+    let transform = Transform2D::from_angle_scale_skew_origin(
+        0.5,
+        Vector2::new(2.0, 3.0),
+        0.25,
+        Vector2::new(7.0, 8.0),
+    );
+
+    assert!(is_equal_approx(transform.get_rotation(), 0.5));
+    assert!(transform
+        .get_scale()
+        .is_equal_approx(Vector2::new(2.0, 3.0)));
+    assert!(is_equal_approx(transform.get_skew(), 0.25));
+    assert_eq!(transform.origin, Vector2::new(7.0, 8.0));
+
+    let orthonormal = transform.orthonormalized();
+    assert!(orthonormal.get_scale().is_equal_approx(Vector2::ONE));
+    assert!(is_equal_approx(orthonormal.get_skew(), 0.0));
+}
+
+#[itest]
+fn transform2d_global_and_local() {
+    let transform = Transform2D::from_angle_origin(FRAC_PI_2, Vector2::new(1.0, 0.0));
+
+    assert!(transform
+        .translated(Vector2::new(1.0, 0.0))
+        .origin
+        .is_equal_approx(Vector2::new(2.0, 0.0)));
+    assert!(transform
+        .translated_local(Vector2::new(1.0, 0.0))
+        .origin
+        .is_equal_approx(Vector2::new(1.0, 1.0)));
+
+    assert!(transform
+        .rotated(PI)
+        .origin
+        .is_equal_approx(Vector2::new(-1.0, 0.0)));
+    assert!(transform
+        .rotated_local(PI)
+        .origin
+        .is_equal_approx(Vector2::new(1.0, 0.0)));
+
+    assert_eq!(
+        transform.scaled(Vector2::new(2.0, 3.0)).origin,
+        Vector2::new(2.0, 0.0)
+    );
+    assert_eq!(
+        transform.scaled_local(Vector2::new(2.0, 3.0)).origin,
+        Vector2::new(1.0, 0.0)
+    );
+}
+
+#[itest]
+fn transform2d_interpolate() {
+    let from = Transform2D::from_angle_origin(0.0, Vector2::ZERO);
+    let to = Transform2D::from_angle_scale_skew_origin(
+        FRAC_PI_2,
+        Vector2::new(3.0, 3.0),
+        0.0,
+        Vector2::new(10.0, 20.0),
+    );
+
+    let halfway = from.interpolate_with(to, 0.5);
+    assert!(is_equal_approx(halfway.get_rotation(), FRAC_PI_2 / 2.0));
+    assert!(halfway.get_scale().is_equal_approx(Vector2::new(2.0, 2.0)));
+    assert!(halfway.origin.is_equal_approx(Vector2::new(5.0, 10.0)));
+    assert!(from.interpolate_with(to, 1.0).is_equal_approx(to));
+}
+
+#[itest]
+fn transform2d_xform_rect() {
+    let rect = Rect2::from_components(0.0, 0.0, 2.0, 1.0);
+    let transform = Transform2D::from_angle_origin(FRAC_PI_2, Vector2::new(5.0, 5.0));
+
+    let transformed = transform * rect;
+    assert!(transformed.is_equal_approx(Rect2::from_components(4.0, 5.0, 1.0, 2.0)));
+    assert!(transform.xform_inv_rect(transformed).is_equal_approx(rect));
+}
+
+#[itest]
+fn transform2d_matches_node2d() {
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(12.0, -3.5));
+    node.set_rotation(0.6);
+    node.set_skew(-0.3);
+    node.set_scale(Vector2::new(1.5, 0.25));
+
+    let expected = Transform2D::from_angle_scale_skew_origin(
+        0.6,
+        Vector2::new(1.5, 0.25),
+        -0.3,
+        Vector2::new(12.0, -3.5),
+    );
+    let engine = node.get_transform();
+    assert!(
+        engine.is_equal_approx(expected),
+        "{engine:?} != {expected:?}"
+    );
+
+    node.free();
+}
+
+fn is_equal_approx(a: f32, b: f32) -> bool {
+    godot::builtin::math::is_equal_approx(a, b)
+}
@@ -0,0 +1,176 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{Basis, EulerOrder, Transform3D, Vector3, AABB};
+use godot::engine::Node3D;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= transform3d_xform();
+    ok &= transform3d_composition_and_inverse();
+    ok &= transform3d_global_and_local();
+    ok &= transform3d_looking_at();
+    ok &= transform3d_interpolate();
+    ok &= transform3d_xform_aabb();
+    ok &= transform3d_matches_node3d();
+    ok
+}
+
+#[itest]
+fn transform3d_xform() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2),
+        Vector3::new(0.0, 0.0, 10.0),
+    );
+
+    let point = transform * Vector3::RIGHT;
+    assert!(point.is_equal_approx(Vector3::new(0.0, 0.0, 9.0)));
+    assert!(transform.xform_inv(point).is_equal_approx(Vector3::RIGHT));
+    assert_eq!(Transform3D::default(), Transform3D::IDENTITY);
+}
+
+#[itest]
+fn transform3d_composition_and_inverse() {
+    let a = Transform3D::new(
+        Basis::from_euler(EulerOrder::YXZ, Vector3::new(0.3, 1.2, -0.4)),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+    let b = Transform3D::IDENTITY
+        .scaled(Vector3::new(2.0, 0.5, 1.0))
+        .translated(Vector3::new(-5.0, 0.0, 1.0));
+
+    let point = Vector3::new(0.5, -1.5, 2.0);
+    assert!(((a * b) * point).is_equal_approx(a * (b * point)));
+
+    let mut accumulated = a;
+    accumulated *= b;
+    assert!(accumulated.is_equal_approx(a * b));
+
+    assert!((a * a.inverse()).is_equal_approx(Transform3D::IDENTITY));
+    assert!((b * b.affine_inverse()).is_equal_approx(Transform3D::IDENTITY));
+    assert!(a.inverse().is_equal_approx(a.affine_inverse()));
+
+    // Singular basis (zero scale) does not panic
+    let singular = Transform3D::new(Basis::from_scale(Vector3::ZERO), Vector3::ONE);
+    assert!(!singular.affine_inverse().is_finite());
+}
+
+#[itest]
+fn transform3d_global_and_local() {
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2),
+        Vector3::new(1.0, 0.0, 0.0),
+    );
+
+    assert!(transform
+        .translated(Vector3::RIGHT)
+        .origin
+        .is_equal_approx(Vector3::new(2.0, 0.0, 0.0)));
+    assert!(transform
+        .translated_local(Vector3::RIGHT)
+        .origin
+        .is_equal_approx(Vector3::new(1.0, 0.0, -1.0)));
+
+    assert!(transform
+        .rotated(Vector3::UP, PI)
+        .origin
+        .is_equal_approx(Vector3::new(-1.0, 0.0, 0.0)));
+    assert!(transform
+        .rotated_local(Vector3::UP, PI)
+        .origin
+        .is_equal_approx(Vector3::new(1.0, 0.0, 0.0)));
+
+    let scale = Vector3::new(2.0, 3.0, 4.0);
+    assert_eq!(transform.scaled(scale).origin, Vector3::new(2.0, 0.0, 0.0));
+    assert_eq!(transform.scaled_local(scale).origin, transform.origin);
+    assert!(transform
+        .scaled_local(scale)
+        .basis
+        .get_scale()
+        .is_equal_approx(scale));
+}
+
+#[itest]
+fn transform3d_looking_at() {
+    let transform = Transform3D::IDENTITY
+        .translated(Vector3::new(1.0, 0.0, 0.0))
+        .looking_at(Vector3::new(1.0, 0.0, -5.0), Vector3::UP);
+    assert!(transform.is_equal_approx(Transform3D::IDENTITY.translated(Vector3::RIGHT)));
+
+    let transform = Transform3D::IDENTITY.looking_at(Vector3::new(3.0, 0.0, 0.0), Vector3::UP);
+    let forward = transform.basis * Vector3::FORWARD;
+    assert!(forward.is_equal_approx(Vector3::RIGHT));
+    assert!((transform.basis * Vector3::UP).is_equal_approx(Vector3::UP));
+
+    // Degenerate inputs do not panic
+    let parallel = Transform3D::IDENTITY.looking_at(Vector3::new(0.0, 2.0, 0.0), Vector3::UP);
+    assert!(!parallel.is_finite());
+    assert!(!Transform3D::IDENTITY
+        .looking_at(Vector3::ZERO, Vector3::UP)
+        .is_finite());
+}
+
+#[itest]
+fn transform3d_interpolate() {
+    let from = Transform3D::IDENTITY;
+    let to = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2).scaled(Vector3::new(3.0, 3.0, 3.0)),
+        Vector3::new(10.0, 0.0, -4.0),
+    );
+
+    let halfway = from.interpolate_with(to, 0.5);
+    let expected = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2 / 2.0).scaled(Vector3::new(2.0, 2.0, 2.0)),
+        Vector3::new(5.0, 0.0, -2.0),
+    );
+    assert!(halfway.is_equal_approx(expected));
+    assert!(from.interpolate_with(to, 1.0).is_equal_approx(to));
+}
+
+#[itest]
+fn transform3d_xform_aabb() {
+    let aabb = AABB::new(Vector3::ZERO, Vector3::new(2.0, 1.0, 1.0));
+    let transform = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2),
+        Vector3::new(0.0, 5.0, 0.0),
+    );
+
+    let transformed = transform * aabb;
+    let expected = AABB::new(Vector3::new(0.0, 5.0, -2.0), Vector3::new(1.0, 1.0, 2.0));
+    assert!(transformed.is_equal_approx(expected));
+    assert!(transform.xform_inv_aabb(transformed).is_equal_approx(aabb));
+}
+
+#[itest]
+fn transform3d_matches_node3d() {
+    let rotation = Vector3::new(0.4, -1.1, 0.25);
+    let scale = Vector3::new(2.0, 0.5, 1.5);
+    let position = Vector3::new(-3.0, 8.0, 0.5);
+
+    let mut node = Node3D::new_alloc();
+    node.set_position(position);
+    node.set_rotation(rotation);
+    node.set_scale(scale);
+
+    // Node3D applies scale first, then rotation in YXZ order
+    let expected = Transform3D::new(
+        Basis::from_euler(EulerOrder::YXZ, rotation) * Basis::from_scale(scale),
+        position,
+    );
+    let engine = node.get_transform();
+    assert!(
+        engine.is_equal_approx(expected),
+        "{engine:?} != {expected:?}"
+    );
+    assert!(engine
+        .basis
+        .get_euler(EulerOrder::YXZ)
+        .is_equal_approx(rotation));
+
+    node.free();
+}
@@ -0,0 +1,103 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{
+    Array, FromVariant, GodotString, ToVariant, TypedArray, Variant, VariantMetadata,
+};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= typed_array_push_get();
+    ok &= typed_array_vec_conversions();
+    ok &= typed_array_from_untyped();
+    ok &= typed_array_variant();
+    ok &= typed_array_engine_enforced();
+    ok
+}
+
+#[itest]
+fn typed_array_push_get() {
+    let mut array = TypedArray::<i64>::new();
+    assert!(array.is_empty());
+
+    array.push(3);
+    array.push(5);
+    array.insert(0, 1);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.get(0), Some(1));
+    assert_eq!(array.get(3), None);
+    assert_eq!(array.front(), Some(1));
+    assert_eq!(array.back(), Some(5));
+
+    array.set(1, 4);
+    assert_eq!(array.remove(1), 4);
+    assert_eq!(array.pop(), Some(5));
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1]);
+
+    array.clear();
+    assert_eq!(array.pop(), None);
+}
+
+#[itest]
+fn typed_array_vec_conversions() {
+    let strings = vec![GodotString::from("a"), GodotString::from("b")];
+
+    let array = TypedArray::from(strings.clone());
+    assert_eq!(array.len(), 2);
+
+    let roundtrip: Vec<GodotString> = array.into();
+    assert_eq!(roundtrip, strings);
+
+    let collected: TypedArray<f64> = [0.5, 1.5].into_iter().collect();
+    let mut sum = 0.0;
+    for value in &collected {
+        sum += value;
+    }
+    assert_eq!(sum, 2.0);
+}
+
+#[itest]
+fn typed_array_from_untyped() {
+    let mut untyped = Array::new();
+    untyped.push(1);
+    untyped.push(2);
+
+    let typed = TypedArray::<i64>::try_from(&untyped).unwrap();
+    assert_eq!(Vec::from(typed), vec![1, 2]);
+
+    untyped.push(GodotString::from("three"));
+    assert!(TypedArray::<i64>::try_from(&untyped).is_err());
+
+    // Any array can be viewed as Array[Variant]
+    let variants = TypedArray::<Variant>::try_from(&untyped).unwrap();
+    assert_eq!(variants.len(), 3);
+}
+
+#[itest]
+fn typed_array_variant() {
+    let array = TypedArray::from(vec![1i64, 2, 3]);
+
+    let variant = array.to_variant();
+    let roundtrip = TypedArray::<i64>::try_from_variant(&variant).unwrap();
+    assert_eq!(roundtrip, array);
+
+    assert!(TypedArray::<GodotString>::try_from_variant(&variant).is_err());
+    assert!(TypedArray::<i64>::try_from_variant(&Variant::nil()).is_err());
+    assert_eq!(TypedArray::<i64>::variant_type(), Array::variant_type());
+}
+
+#[itest]
+fn typed_array_engine_enforced() {
+    let array = TypedArray::from(vec![1i64, 2]);
+
+    // The untyped view shares the array, whose element type is checked by Godot
+    let mut shared = array.as_untyped().clone();
+    shared.push(GodotString::from("rejected"));
+    shared.push(3);
+
+    assert_eq!(Vec::from(array), vec![1, 2, 3]);
+}
@@ -4,11 +4,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::itest;
-use godot::builtin::{FromVariant, GodotString, StringName, ToVariant, Variant, Vector2, Vector3};
-use godot::obj::InstanceId;
+use crate::{expect_panic, itest};
+use godot::builtin::{
+    Array, CallError, Dictionary, FromVariant, GodotString, Int32Array, StringName, ToVariant,
+    TypedArray, Variant, VariantConversionReason, VariantDispatch, VariantMetadata, Vector2,
+    Vector3,
+};
+use godot::engine::{Node, Node3D};
+use godot::obj::{Gd, InstanceId, Share};
 use godot::sys::{GodotFfi, VariantOperator, VariantType};
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Debug, Display};
 
 pub fn run() -> bool {
@@ -16,11 +22,28 @@ pub fn run() -> bool {
     ok &= variant_nil();
     ok &= variant_conversions();
     ok &= variant_forbidden_conversions();
+    ok &= variant_conversion_error();
+    ok &= variant_strict_conversions();
+    ok &= variant_coerced_conversions();
+    ok &= variant_std_conversions();
+    ok &= variant_std_conversion_errors();
+    ok &= variant_option_object();
     ok &= variant_display();
     ok &= variant_get_type();
+    ok &= variant_dispatch();
     ok &= variant_equal();
     ok &= variant_evaluate();
     ok &= variant_evaluate_total_order();
+    ok &= variant_operators();
+    ok &= variant_checked_operators();
+    ok &= variant_partial_ord();
+    ok &= variant_hash();
+    ok &= variant_booleanize();
+    ok &= variant_call();
+    ok &= variant_call_errors();
+    ok &= variant_get_set();
+    ok &= variant_get_set_indexed();
+    ok &= variant_iter();
     ok &= variant_sys_conversion();
     ok &= variant_sys_conversion2();
     ok
@@ -41,8 +64,8 @@ fn variant_conversions() {
     roundtrip(true);
     roundtrip(gstr("some string"));
     roundtrip(InstanceId::from_nonzero(-9223372036854775808i64));
-    // roundtrip(Some(InstanceId::from_nonzero(9223372036854775807i64)));
-    // roundtrip(Option::<InstanceId>::None);
+    roundtrip(Some(InstanceId::from_nonzero(9223372036854775807i64)));
+    roundtrip(Option::<InstanceId>::None);
 
     // unsigned
     roundtrip(0u8);
@@ -67,6 +90,138 @@ fn variant_forbidden_conversions() {
     truncate_bad::<i8>(128);
 }
 
+#[itest]
+fn variant_conversion_error() {
+    let err = i8::try_from_variant(&300.to_variant()).unwrap_err();
+    assert_eq!(err.expected(), VariantType::Int);
+    assert_eq!(err.actual(), VariantType::Int);
+    assert_eq!(err.value(), "300");
+    assert_eq!(err.reason(), VariantConversionReason::OutOfRange);
+    assert_eq!(
+        err.to_string(),
+        "cannot convert Int value `300` to Int: value out of range"
+    );
+
+    let err = InstanceId::try_from_variant(&0.to_variant()).unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::OutOfRange);
+
+    let array = Array::from_iter([1.to_variant(), gstr("two").to_variant()]);
+    let err = TypedArray::<i64>::try_from(&array).unwrap_err();
+    assert_eq!(err.expected(), VariantType::Int);
+    assert_eq!(err.actual(), VariantType::String);
+    assert_eq!(err.value(), "two");
+    assert_eq!(err.reason(), VariantConversionReason::BadType);
+
+    let node = Node::new_alloc();
+    let array = Array::from_iter([node.to_variant()]);
+    let err = TypedArray::<Gd<Node3D>>::try_from(&array).unwrap_err();
+    assert_eq!(err.expected(), VariantType::Object);
+    assert_eq!(
+        err.reason(),
+        VariantConversionReason::WrongClass {
+            expected_class: "Node3D"
+        }
+    );
+    node.free();
+
+    let err = TypedArray::<i64>::try_from_variant(&gstr("[]").to_variant()).unwrap_err();
+    assert_eq!(err.expected(), VariantType::Array);
+    assert_eq!(err.actual(), VariantType::String);
+    assert_eq!(err.reason(), VariantConversionReason::BadType);
+}
+
+#[itest]
+fn variant_strict_conversions() {
+    strict_bad::<Vector2>(gstr("(1, 2)"));
+    strict_bad::<GodotString>(gname("name"));
+    strict_bad::<i64>(1.5);
+    strict_bad::<i32>(true);
+    strict_bad::<f64>(1);
+    strict_bad::<f32>(1);
+    strict_bad::<bool>(0);
+    strict_bad::<Array>(Variant::nil());
+}
+
+#[itest]
+fn variant_coerced_conversions() {
+    assert_eq!(2.7.to_variant().try_to_coerced::<i64>(), Ok(2));
+    assert_eq!((-2.7).to_variant().try_to_coerced::<i8>(), Ok(-2));
+    assert_eq!(true.to_variant().try_to_coerced::<i32>(), Ok(1));
+    assert_eq!(3.to_variant().try_to_coerced::<f64>(), Ok(3.0));
+    assert_eq!(false.to_variant().try_to_coerced::<f32>(), Ok(0.0));
+    assert_eq!(0.to_variant().try_to_coerced::<bool>(), Ok(false));
+    assert_eq!(0.1.to_variant().try_to_coerced::<bool>(), Ok(true));
+    assert_eq!(7.to_variant().try_to_coerced::<i64>(), Ok(7));
+
+    // Ranges are still checked, and other types are converted strictly
+    let err = 300.0.to_variant().try_to_coerced::<u8>().unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::OutOfRange);
+    let err = gstr("5").to_variant().try_to_coerced::<i64>().unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::BadType);
+}
+
+#[itest]
+fn variant_std_conversions() {
+    roundtrip(Some(7i64));
+    roundtrip(None::<i64>);
+    roundtrip(Box::new(gstr("boxed")));
+    roundtrip(vec![1i64, -2, 3]);
+    roundtrip(Vec::<GodotString>::new());
+    roundtrip([0.5f64, -1.25]);
+    roundtrip((7i64, gstr("seven"), true));
+    roundtrip(HashMap::from([(gstr("one"), 1i64), (gstr("two"), 2)]));
+    roundtrip(BTreeMap::from([(1i64, vec![true]), (2, vec![])]));
+
+    assert!(None::<i64>.to_variant().is_nil());
+    assert_eq!(vec![1i64, 2].to_variant().get_type(), VariantType::Array);
+    assert_eq!([1i64, 2][..].to_variant(), vec![1i64, 2].to_variant());
+    assert_eq!((1i64, 2i64).to_variant(), [1i64, 2].to_variant());
+
+    let map = HashMap::from([(1i64, gstr("one"))]);
+    let dictionary = Dictionary::try_from_variant(&map.to_variant()).unwrap();
+    assert_eq!(dictionary.get(1), Some(gstr("one").to_variant()));
+}
+
+#[itest]
+fn variant_std_conversion_errors() {
+    let variant = Array::from_iter([1.to_variant(), gstr("two").to_variant()]).to_variant();
+    let err = Vec::<i64>::try_from_variant(&variant).unwrap_err();
+    assert_eq!(err.actual(), VariantType::String);
+    assert_eq!(err.reason(), VariantConversionReason::BadType);
+
+    // Arrays of the wrong length
+    let variant = vec![1i64, 2, 3].to_variant();
+    let err = <[i64; 2]>::try_from_variant(&variant).unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::OutOfRange);
+    let err = <(i64, i64)>::try_from_variant(&variant).unwrap_err();
+    assert_eq!(err.reason(), VariantConversionReason::OutOfRange);
+
+    strict_bad::<Vec<i64>>(Dictionary::new());
+    strict_bad::<HashMap<i64, i64>>(Array::new());
+    strict_bad::<Option<i64>>(gstr("seven"));
+    strict_bad::<(i64,)>(7);
+}
+
+#[itest]
+fn variant_option_object() {
+    let node = Node::new_alloc();
+    let back = Option::<Gd<Node>>::try_from_variant(&Some(node.share()).to_variant()).unwrap();
+    assert_eq!(back.map(|n| n.instance_id()), Some(node.instance_id()));
+
+    let back = Option::<Gd<Node>>::try_from_variant(&Variant::nil()).unwrap();
+    assert!(back.is_none());
+
+    // Only objects can be null in Godot, other optional values are passed as Variant
+    assert_eq!(
+        Option::<Gd<Node>>::variant_type(),
+        Gd::<Node>::variant_type()
+    );
+    assert_eq!(Option::<Gd<Node>>::class_name(), "Node");
+    assert_eq!(Option::<i64>::variant_type(), Variant::variant_type());
+
+    node.free();
+}
+
 #[itest]
 fn variant_get_type() {
     let variant = Variant::nil();
@@ -82,6 +237,41 @@ fn variant_get_type() {
     assert_eq!(variant.get_type(), VariantType::String);
 }
 
+#[itest]
+fn variant_dispatch() {
+    assert_eq!(Variant::nil().dispatch(), VariantDispatch::Nil);
+    assert_eq!(7.to_variant().dispatch(), VariantDispatch::Int(7));
+    assert_eq!(2.5.to_variant().dispatch(), VariantDispatch::Float(2.5));
+    assert_eq!(
+        gstr("hi").to_variant().dispatch(),
+        VariantDispatch::String(gstr("hi"))
+    );
+
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    match vector.to_variant().dispatch() {
+        VariantDispatch::Vector3(v) => assert_eq!(v, vector),
+        other => panic!("unexpected dispatch {other:?}"),
+    }
+
+    let node = Node::new_alloc();
+    let variant = node.share().to_variant();
+    let dispatch = variant.dispatch();
+    match dispatch.clone() {
+        VariantDispatch::Object(object) => assert_eq!(object.instance_id(), node.instance_id()),
+        other => panic!("unexpected dispatch {other:?}"),
+    }
+    assert_eq!(Variant::from(dispatch), variant);
+
+    node.free();
+    assert_eq!(variant.dispatch(), VariantDispatch::Nil);
+
+    // Roundtrip
+    let array = Array::from_iter([1.to_variant(), gstr("two").to_variant()]);
+    let variant = array.to_variant();
+    assert_eq!(variant.dispatch().to_variant(), variant);
+    assert_ne!(VariantDispatch::Int(1), VariantDispatch::Float(1.0));
+}
+
 #[itest]
 fn variant_equal() {
     assert_eq!(Variant::nil(), ().to_variant());
@@ -147,6 +337,196 @@ fn variant_evaluate_total_order() {
     total_order(gstr("hello"), gstr("hell"), Ordering::Greater);
 }
 
+#[itest]
+fn variant_operators() {
+    let a = 7.to_variant();
+    let b = 2.to_variant();
+
+    assert_eq!(&a + &b, 9.to_variant());
+    assert_eq!(&a - &b, 5.to_variant());
+    assert_eq!(&a * &b, 14.to_variant());
+    assert_eq!(&a / &b, 3.to_variant());
+    assert_eq!(&a % &b, 1.to_variant());
+    assert_eq!(&a & &b, 2.to_variant());
+    assert_eq!(&a | &b, 7.to_variant());
+    assert_eq!(&a ^ &b, 5.to_variant());
+    assert_eq!(&a << &b, 28.to_variant());
+    assert_eq!(&a >> &b, 1.to_variant());
+    assert_eq!(-&a, (-7).to_variant());
+    assert_eq!(!&a, false.to_variant());
+    assert_eq!(!Variant::nil(), true.to_variant());
+
+    // Result type follows GDScript rules
+    assert_eq!(a + 0.5.to_variant(), 7.5.to_variant());
+    assert_eq!(
+        gstr("ab").to_variant() + gstr("cd").to_variant(),
+        gstr("abcd").to_variant()
+    );
+
+    expect_panic("int + Vector3", || {
+        let _ = 1.to_variant() + Vector3::new(1.0, 2.0, 3.0).to_variant();
+    });
+    expect_panic("division by zero", || {
+        let _ = 1.to_variant() / 0.to_variant();
+    });
+}
+
+#[itest]
+fn variant_checked_operators() {
+    let a = 7.to_variant();
+
+    assert_eq!(a.checked_add(&1.to_variant()), Some(8.to_variant()));
+    assert_eq!(a.checked_neg(), Some((-7).to_variant()));
+    assert_eq!(a.checked_sub(&gstr("x").to_variant()), None);
+    assert_eq!(a.checked_div(&0.to_variant()), None);
+    assert_eq!(gstr("x").to_variant().checked_neg(), None);
+}
+
+#[itest]
+fn variant_partial_ord() {
+    let one = 1.to_variant();
+    let two = 2.to_variant();
+
+    assert!(one < two);
+    assert!(two > one);
+    assert_eq!(one.partial_cmp(&one), Some(Ordering::Equal));
+    assert_eq!(
+        gstr("b").to_variant().partial_cmp(&gstr("a").to_variant()),
+        Some(Ordering::Greater)
+    );
+
+    // Different types: either incomparable, or equal in GDScript but not under PartialEq
+    assert_eq!(one.partial_cmp(&gstr("1").to_variant()), None);
+    assert_eq!(one.partial_cmp(&1.0.to_variant()), None);
+    assert!(one < 1.5.to_variant());
+}
+
+#[itest]
+fn variant_hash() {
+    let mut set = HashSet::new();
+    set.insert(1.to_variant());
+    set.insert(1.to_variant());
+    set.insert(1.0.to_variant());
+    set.insert(gstr("1").to_variant());
+    set.insert(Variant::nil());
+
+    assert_eq!(set.len(), 4);
+    assert!(set.contains(&gstr("1").to_variant()));
+
+    // Unlike GDScript's ==, equality is reflexive for NaN
+    let nan = f64::NAN.to_variant();
+    assert_eq!(nan, nan.clone());
+    assert_ne!(1.to_variant(), 1.0.to_variant());
+    assert!(1.to_variant().hash_compare(&1.to_variant()));
+}
+
+#[itest]
+fn variant_booleanize() {
+    assert!(!Variant::nil().booleanize());
+    assert!(!false.to_variant().booleanize());
+    assert!(!0.to_variant().booleanize());
+    assert!(!0.0.to_variant().booleanize());
+    assert!(!gstr("").to_variant().booleanize());
+
+    assert!(true.to_variant().booleanize());
+    assert!((-1).to_variant().booleanize());
+    assert!(gstr("false").to_variant().booleanize());
+}
+
+#[itest]
+fn variant_call() {
+    let mut string = gstr("hello").to_variant();
+    assert_eq!(string.call("to_upper", &[]), Ok(gstr("HELLO").to_variant()));
+    assert_eq!(
+        string.call("substr", &[1.to_variant(), 3.to_variant()]),
+        Ok(gstr("ell").to_variant())
+    );
+    assert!(string.has_method("to_upper"));
+    assert!(!string.has_method("no_such_method"));
+
+    // Value types are modified in place
+    let mut packed = Int32Array::new().to_variant();
+    packed.call("push_back", &[5.to_variant()]).unwrap();
+    assert_eq!(packed.call("size", &[]), Ok(1.to_variant()));
+    assert_eq!(Int32Array::from_variant(&packed).as_slice(), &[5]);
+}
+
+#[itest]
+fn variant_call_errors() {
+    let mut string = gstr("hello").to_variant();
+
+    assert_eq!(
+        string.call("no_such_method", &[]),
+        Err(CallError::InvalidMethod)
+    );
+    assert_eq!(
+        string.call("substr", &[gstr("1").to_variant()]),
+        Err(CallError::InvalidArgument {
+            index: 0,
+            expected: VariantType::Int
+        })
+    );
+    assert!(matches!(
+        string.call("substr", &[]),
+        Err(CallError::TooFewArguments { .. })
+    ));
+    assert!(matches!(
+        string.call("to_upper", &[1.to_variant()]),
+        Err(CallError::TooManyArguments { .. })
+    ));
+}
+
+#[itest]
+fn variant_get_set() {
+    let mut vector = Vector2::new(1.0, 2.0).to_variant();
+    assert_eq!(vector.get("x"), Some(1.0.to_variant()));
+    assert_eq!(vector.get("z"), None);
+
+    assert!(vector.set("y", 5.0));
+    assert!(!vector.set("z", 5.0));
+    assert!(!vector.set("x", gstr("five")));
+    assert_eq!(vector, Vector2::new(1.0, 5.0).to_variant());
+
+    let mut dict = Dictionary::new().to_variant();
+    assert!(dict.set("key", 7));
+    assert_eq!(dict.get("key"), Some(7.to_variant()));
+    assert_eq!(dict.get("missing"), None);
+}
+
+#[itest]
+fn variant_get_set_indexed() {
+    let mut array = Array::from_iter([10, 20]).to_variant();
+    assert_eq!(array.get_indexed(1), Some(20.to_variant()));
+    assert_eq!(array.get_indexed(2), None);
+
+    assert!(array.set_indexed(0, 5));
+    assert!(!array.set_indexed(2, 5));
+    assert_eq!(array.get_indexed(0), Some(5.to_variant()));
+
+    let mut int = 7.to_variant();
+    assert_eq!(int.get_indexed(0), None);
+    assert!(!int.set_indexed(0, 1));
+}
+
+#[itest]
+fn variant_iter() {
+    let array = Array::from_iter([1, 2, 3]).to_variant();
+    let elements: Vec<i64> = array.iter().map(|v| v.to()).collect();
+    assert_eq!(elements, vec![1, 2, 3]);
+
+    let range: Vec<i64> = 3.to_variant().iter().map(|v| v.to()).collect();
+    assert_eq!(range, vec![0, 1, 2]);
+
+    let mut dict = Dictionary::new();
+    dict.insert("a", 1);
+    dict.insert("b", 2);
+    let keys: Vec<Variant> = dict.to_variant().iter().collect();
+    assert_eq!(keys, vec![gstr("a").to_variant(), gstr("b").to_variant()]);
+
+    assert_eq!(Array::new().to_variant().iter().count(), 0);
+    assert_eq!(true.to_variant().iter().count(), 0);
+}
+
 #[itest]
 fn variant_display() {
     let cases = [
@@ -215,7 +595,7 @@ where
     T: FromVariant + ToVariant + PartialEq + Debug,
 {
     // TODO test other roundtrip (first FromVariant, then ToVariant)
-    // Some values can be represented in Variant, but not in T (e.g. Variant(0i64) -> Option<InstanceId> -> Variant is lossy)
+    // Some values can be represented in Variant, but not in T (e.g. Variant(TypedArray) -> Vec<T> -> Variant loses the element type)
 
     let variant = value.to_variant();
     let back = T::try_from_variant(&variant).unwrap();
@@ -240,6 +620,20 @@ where
     }
 }
 
+fn strict_bad<T>(value: impl ToVariant)
+where
+    T: FromVariant + Debug,
+{
+    let variant = value.to_variant();
+    let err = T::try_from_variant(&variant).expect_err(&format!(
+        "{} - T::try_from_variant({variant:?}) should fail",
+        std::any::type_name::<T>()
+    ));
+
+    assert_eq!(err.reason(), VariantConversionReason::BadType);
+    assert_eq!(err.actual(), variant.get_type());
+}
+
 fn equal<T, U>(lhs: T, rhs: U, expected: bool)
 where
     T: ToVariant,
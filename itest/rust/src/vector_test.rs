@@ -0,0 +1,273 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::itest;
+use godot::builtin::{
+    Vector2, Vector2Axis, Vector2i, Vector3, Vector3Axis, Vector3i, Vector4, Vector4Axis, Vector4i,
+};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+pub fn run() -> bool {
+    let mut ok = true;
+    ok &= vector2_length();
+    ok &= vector2_operators();
+    ok &= vector2_angles();
+    ok &= vector2_interpolation();
+    ok &= vector2_reflection();
+    ok &= vector2_rounding();
+    ok &= vector3_cross();
+    ok &= vector3_angles();
+    ok &= vector3_slerp();
+    ok &= vector4_operators();
+    ok &= vector2i_operators();
+    ok &= vector2i_conversions();
+    ok &= vector3i_axes();
+    ok &= vector4i_operators();
+    ok
+}
+
+#[itest]
+fn vector2_length() {
+    let vec = Vector2::new(3.0, 4.0);
+
+    assert_eq!(vec.length(), 5.0);
+    assert_eq!(vec.length_squared(), 25.0);
+    assert!(vec.normalized().is_equal_approx(Vector2::new(0.6, 0.8)));
+    assert!(vec.normalized().is_normalized());
+    assert_eq!(Vector2::ZERO.normalized(), Vector2::ZERO);
+    assert_eq!(vec.distance_to(Vector2::ZERO), 5.0);
+    assert!(vec
+        .limit_length(2.5)
+        .is_equal_approx(Vector2::new(1.5, 2.0)));
+}
+
+#[itest]
+fn vector2_operators() {
+    let a = Vector2::new(1.0, 2.0);
+    let b = Vector2::new(3.0, -4.0);
+
+    assert_eq!(a + b, Vector2::new(4.0, -2.0));
+    assert_eq!(a - b, Vector2::new(-2.0, 6.0));
+    assert_eq!(a * b, Vector2::new(3.0, -8.0));
+    assert_eq!(b / a, Vector2::new(3.0, -2.0));
+    assert_eq!(-a, Vector2::new(-1.0, -2.0));
+    assert_eq!(a * 2.0, Vector2::new(2.0, 4.0));
+    assert_eq!(2.0 * a, Vector2::new(2.0, 4.0));
+    assert_eq!(b / 2.0, Vector2::new(1.5, -2.0));
+
+    let mut c = a;
+    c += b;
+    c *= 2.0;
+    assert_eq!(c, Vector2::new(8.0, -4.0));
+
+    assert_eq!(a.dot(b), -5.0);
+    assert_eq!(a.cross(b), -10.0);
+}
+
+#[itest]
+fn vector2_angles() {
+    assert_eq!(Vector2::RIGHT.angle(), 0.0);
+    assert!(is_equal_approx(Vector2::DOWN.angle(), FRAC_PI_2));
+    assert!(is_equal_approx(
+        Vector2::RIGHT.angle_to(Vector2::DOWN),
+        FRAC_PI_2
+    ));
+    assert!(is_equal_approx(
+        Vector2::DOWN.angle_to(Vector2::RIGHT),
+        -FRAC_PI_2
+    ));
+    assert!(Vector2::RIGHT
+        .rotated(FRAC_PI_2)
+        .is_equal_approx(Vector2::DOWN));
+    assert_eq!(Vector2::new(2.0, 5.0).orthogonal(), Vector2::new(5.0, -2.0));
+}
+
+#[itest]
+fn vector2_interpolation() {
+    let from = Vector2::new(0.0, 0.0);
+    let to = Vector2::new(10.0, -20.0);
+
+    assert_eq!(from.lerp(to, 0.25), Vector2::new(2.5, -5.0));
+    assert_eq!(from.move_toward(to, 100.0), to);
+    assert!(Vector2::ZERO
+        .move_toward(Vector2::new(3.0, 4.0), 2.5)
+        .is_equal_approx(Vector2::new(1.5, 2.0)));
+
+    let halfway = Vector2::RIGHT.slerp(Vector2::DOWN * 3.0, 0.5);
+    assert!(is_equal_approx(halfway.length(), 2.0));
+    assert!(is_equal_approx(halfway.angle(), FRAC_PI_2 / 2.0));
+}
+
+#[itest]
+fn vector2_reflection() {
+    let vec = Vector2::new(1.0, -1.0);
+    let normal = Vector2::UP;
+
+    assert_eq!(vec.bounce(normal), Vector2::new(1.0, 1.0));
+    assert_eq!(vec.reflect(normal), Vector2::new(-1.0, -1.0));
+    assert_eq!(vec.slide(normal), Vector2::new(1.0, 0.0));
+    assert_eq!(
+        Vector2::new(3.0, 5.0).project(Vector2::new(2.0, 0.0)),
+        Vector2::new(3.0, 0.0)
+    );
+}
+
+#[itest]
+fn vector2_rounding() {
+    let vec = Vector2::new(-1.5, 2.5);
+
+    assert_eq!(vec.abs(), Vector2::new(1.5, 2.5));
+    assert_eq!(vec.floor(), Vector2::new(-2.0, 2.0));
+    assert_eq!(vec.ceil(), Vector2::new(-1.0, 3.0));
+    assert_eq!(vec.round(), Vector2::new(-2.0, 3.0));
+    assert_eq!(vec.sign(), Vector2::new(-1.0, 1.0));
+    assert_eq!(
+        Vector2::new(7.3, -2.2).snapped(Vector2::new(2.0, 0.5)),
+        Vector2::new(8.0, -2.0)
+    );
+    assert_eq!(
+        vec.clamp(Vector2::new(-1.0, 0.0), Vector2::new(1.0, 2.0)),
+        Vector2::new(-1.0, 2.0)
+    );
+}
+
+#[itest]
+fn vector3_cross() {
+    assert_eq!(Vector3::RIGHT.cross(Vector3::UP), Vector3::BACK);
+    assert_eq!(Vector3::UP.cross(Vector3::RIGHT), Vector3::FORWARD);
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).dot(Vector3::ONE), 6.0);
+}
+
+#[itest]
+fn vector3_angles() {
+    assert!(is_equal_approx(
+        Vector3::RIGHT.angle_to(Vector3::UP),
+        FRAC_PI_2
+    ));
+    assert!(is_equal_approx(
+        Vector3::RIGHT.signed_angle_to(Vector3::FORWARD, Vector3::DOWN),
+        -FRAC_PI_2
+    ));
+    assert!(Vector3::RIGHT
+        .rotated(Vector3::UP, PI)
+        .is_equal_approx(Vector3::LEFT));
+}
+
+#[itest]
+fn vector3_slerp() {
+    let halfway = Vector3::RIGHT.slerp(Vector3::UP, 0.5);
+    let expected = Vector3::new(1.0, 1.0, 0.0).normalized();
+    assert!(halfway.is_equal_approx(expected));
+
+    // Colinear vectors fall back to lerp
+    let colinear = Vector3::RIGHT.slerp(Vector3::RIGHT * 3.0, 0.5);
+    assert_eq!(colinear, Vector3::RIGHT * 2.0);
+}
+
+#[itest]
+fn vector4_operators() {
+    let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Vector4::ONE;
+
+    assert_eq!(a + b, Vector4::new(2.0, 3.0, 4.0, 5.0));
+    assert_eq!(a * 0.5, Vector4::new(0.5, 1.0, 1.5, 2.0));
+    assert_eq!(a.dot(b), 10.0);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert!(Vector4::new(2.0, 0.0, 0.0, 0.0)
+        .normalized()
+        .is_equal_approx(Vector4::new(1.0, 0.0, 0.0, 0.0)));
+}
+
+#[itest]
+fn vector2i_operators() {
+    let a = Vector2i::new(7, -9);
+    let b = Vector2i::new(2, 4);
+
+    assert_eq!(a + b, Vector2i::new(9, -5));
+    assert_eq!(a - b, Vector2i::new(5, -13));
+    assert_eq!(a * b, Vector2i::new(14, -36));
+    assert_eq!(a / b, Vector2i::new(3, -2));
+    assert_eq!(a % b, Vector2i::new(1, -1));
+    assert_eq!(a % 3, Vector2i::new(1, 0));
+    assert_eq!(3 * b, Vector2i::new(6, 12));
+    assert_eq!(-a, Vector2i::new(-7, 9));
+
+    // Wraps around on overflow, like in Godot
+    assert_eq!(
+        Vector2i::new(i32::MAX, 0) + Vector2i::ONE,
+        Vector2i::new(i32::MIN, 1)
+    );
+    assert_eq!(-Vector2i::new(i32::MIN, 0), Vector2i::new(i32::MIN, 0));
+
+    let mut c = a;
+    c %= b;
+    c -= Vector2i::ONE;
+    assert_eq!(c, Vector2i::new(0, -2));
+
+    assert_eq!(a.abs(), Vector2i::new(7, 9));
+    assert_eq!(Vector2i::new(-3, 0).sign(), Vector2i::new(-1, 0));
+    assert_eq!(a.min(b), Vector2i::new(2, -9));
+    assert_eq!(a.max(b), Vector2i::new(7, 4));
+    assert_eq!(
+        a.clamp(Vector2i::ZERO, Vector2i::new(5, 5)),
+        Vector2i::new(5, 0)
+    );
+    assert_eq!(Vector2i::new(3, 4).length_squared(), 25);
+    assert_eq!(Vector2i::new(50000, 0).length_squared(), 2_500_000_000);
+    assert_eq!(Vector2i::new(3, 4).length(), 5.0);
+}
+
+#[itest]
+fn vector2i_conversions() {
+    let vec = Vector2i::from_vector2(Vector2::new(2.9, -2.9));
+    assert_eq!(vec, Vector2i::new(2, -2));
+    assert_eq!(vec.to_vector2(), Vector2::new(2.0, -2.0));
+    assert_eq!(Vector2::from_vector2i(Vector2i::DOWN), Vector2::DOWN);
+
+    let mut vec = Vector2i::default();
+    vec.set_x(-1);
+    vec.set_y(5);
+    assert_eq!((vec.x(), vec.y()), (-1, 5));
+    assert_eq!(vec.get(Vector2Axis::Y), 5);
+    assert_eq!(vec.max_axis(), Vector2Axis::Y);
+    assert_eq!(vec.min_axis(), Vector2Axis::X);
+}
+
+#[itest]
+fn vector3i_axes() {
+    let vec = Vector3i::new(4, -8, 2);
+
+    assert_eq!(vec.get(Vector3Axis::Z), 2);
+    assert_eq!(vec.max_axis(), Vector3Axis::X);
+    assert_eq!(vec.min_axis(), Vector3Axis::Y);
+    assert_eq!(
+        Vector3i::from_vector3(Vector3::new(1.5, -0.5, 100.99)),
+        Vector3i::new(1, 0, 100)
+    );
+    assert_eq!(Vector3::from_vector3i(Vector3i::BACK), Vector3::BACK);
+}
+
+#[itest]
+fn vector4i_operators() {
+    let a = Vector4i::new(1, 2, 3, 4);
+
+    assert_eq!(a + Vector4i::ONE, Vector4i::new(2, 3, 4, 5));
+    assert_eq!(a * 2, Vector4i::new(2, 4, 6, 8));
+    assert_eq!(a % 2, Vector4i::new(1, 0, 1, 0));
+    assert_eq!(a.w(), 4);
+    assert_eq!(a.max_axis(), Vector4Axis::W);
+    assert_eq!(a.min_axis(), Vector4Axis::X);
+    assert_eq!(Vector4i::ONE.max_axis(), Vector4Axis::X);
+    assert_eq!(
+        Vector4i::from_vector4(Vector4::new(-1.5, 0.5, 2.5, 3.0)),
+        Vector4i::new(-1, 0, 2, 3)
+    );
+    assert_eq!(a.to_vector4(), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+fn is_equal_approx(a: f32, b: f32) -> bool {
+    godot::builtin::math::is_equal_approx(a, b)
+}